@@ -4,7 +4,8 @@
 
 use std::num::ParseFloatError;
 use std::num::ParseIntError;
-use std::sync::Once;
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
 
 use crate::time_scales::Tdb;
 
@@ -19,25 +20,159 @@ pub struct Annus {
     pub moon_phase: [[Tdb; 4]; 15],
 }
 
-static mut DATA: Vec<Annus> = Vec::new();
-static INIT: Once = Once::new();
+/// 載入曆表數據的歲序號範圍，其外 [`Annus::get`] 均返回 `None`。
+///
+/// 原始數據涵蓋 1600 至 3500 年，但 1929 年以前曆法以北京地方時為準，與本庫所用東經 120° 標準時不同；
+/// 遠期的 ΔT 亦難以預測，故僅載入其中一段。
+pub const ANNUS_RANGE: RangeInclusive<i32> = 1950..=2100;
+
+static DATA: OnceLock<Vec<Annus>> = OnceLock::new();
+
+/// 全部曆表數據，依序號排列。
+fn data() -> &'static [Annus] {
+    DATA.get_or_init(|| {
+        parse_raw_data().unwrap_or_else(|e| panic!("error parsing ephemeris data: {}", e))
+    })
+}
 
 impl Annus {
     /// 取得公元 `annus` 年對應的歳的曆表。
     ///
     /// 無數據則返回 `None`。
     pub fn get(annus: i32) -> Option<&'static Self> {
-        INIT.call_once(|| {
-            let res =
-                parse_raw_data().unwrap_or_else(|e| panic!("error parsing ephemeris data: {}", e));
-            unsafe {
-                DATA = res;
+        let data = data();
+        data.binary_search_by_key(&annus, |an| an.annus)
+            .ok()
+            .map(|i| &data[i])
+    }
+    /// 依次取得十五個月的月相時刻，同 [`moon_phase`](Self::moon_phase)，首月以冬至前一朔為月首。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::ephemeris::Annus;
+    ///
+    /// let annus = Annus::get(2000).unwrap();
+    /// let [new_moon, first_quarter, full_moon, last_quarter] = annus.moon_phases().next().unwrap();
+    ///
+    /// assert!(new_moon < first_quarter && first_quarter < full_moon && full_moon < last_quarter);
+    /// assert_eq!(15, annus.moon_phases().count());
+    /// ```
+    pub fn moon_phases(&self) -> impl Iterator<Item = [Tdb; 4]> + '_ {
+        self.moon_phase.iter().copied()
+    }
+    /// 取得第 `i` 個朔的時刻，`i` 自 0 起算，0 為冬至前一朔。`i` 不在 `0..15` 間時返回 `None`。
+    pub fn nth_new_moon(&self, i: usize) -> Option<Tdb> {
+        self.moon_phase.get(i).map(|phases| phases[0])
+    }
+}
+
+/// 檢查內置曆表數據是否完整自洽，以免數據損壞時 [`Annus::new`](super::Annus::new) 等 panic。
+///
+/// 檢查各歲兩冬至間有十二或十三個月、節氣及月相時刻依次遞增、各歲序號相連且前歲末冬至即次歲首冬至。返回全部問題。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::ephemeris;
+///
+/// assert_eq!(Ok(()), ephemeris::validate());
+/// ```
+pub fn validate() -> Result<(), Vec<ValidationIssue>> {
+    validate_data(data())
+}
+
+fn validate_data(data: &[Annus]) -> Result<(), Vec<ValidationIssue>> {
+    use ValidationIssue::*;
+    let mut issues = Vec::new();
+    for an in data {
+        let annus = an.annus;
+        if let Some(index) = (1..25).find(|&i| an.solar_term[i - 1] >= an.solar_term[i]) {
+            issues.push(SolarTermOrder { annus, index });
+        }
+        let phases: Vec<_> = an.moon_phases().flatten().collect();
+        if let Some(i) = (1..phases.len()).find(|&i| phases[i - 1] >= phases[i]) {
+            let index = (i / 4, i % 4);
+            issues.push(MoonPhaseOrder { annus, index });
+        }
+        // 與 `Annus::from_ephemeris` 同樣依北京時間日期計算
+        let new_moons: Vec<_> = an.moon_phases().map(|p| super::date_cst(p[0])).collect();
+        let ws = super::date_cst(an.solar_term[0]);
+        let ws_next = super::date_cst(an.solar_term[24]);
+        let m11 = new_moons.partition_point(|date| date <= &ws);
+        let m11n = new_moons.partition_point(|date| date <= &ws_next);
+        if m11 == 0 || m11n == new_moons.len() {
+            issues.push(NewMoonCoverage { annus });
+        } else if !(12..=13).contains(&(m11n - m11)) {
+            let months = m11n - m11;
+            issues.push(MonthCount { annus, months });
+        }
+    }
+    for pair in data.windows(2) {
+        let (prev, next) = (&pair[0], &pair[1]);
+        if prev.annus.checked_add(1) != Some(next.annus) {
+            issues.push(Gap {
+                prev: prev.annus,
+                next: next.annus,
+            });
+        } else if (prev.solar_term[24].0 - next.solar_term[0].0).abs() > 1.0 / 86400.0 {
+            issues.push(SolsticeMismatch { annus: next.annus });
+        }
+    }
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+/// 曆表數據的問題，見 [`validate`]。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ValidationIssue {
+    /// 兩冬至間不是十二或十三個月
+    MonthCount { annus: i32, months: usize },
+    /// 月相數據未涵蓋冬至所在月，或下一冬至所在月之後無朔
+    NewMoonCoverage { annus: i32 },
+    /// 節氣時刻不遞增，`index` 為首個不大於前一項者
+    SolarTermOrder { annus: i32, index: usize },
+    /// 月相時刻不遞增，`index` 為首個不大於前一項者，格式同 [`Annus::moon_phase`]
+    MoonPhaseOrder { annus: i32, index: (usize, usize) },
+    /// 相鄰兩條數據的序號不相連
+    Gap { prev: i32, next: i32 },
+    /// 前一歲末冬至與本歲首冬至時刻不符
+    SolsticeMismatch { annus: i32 },
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ValidationIssue::*;
+        match *self {
+            MonthCount { annus, months } => {
+                write!(
+                    f,
+                    "annus {}: {} months between winter solstices",
+                    annus, months
+                )
+            }
+            NewMoonCoverage { annus } => {
+                write!(
+                    f,
+                    "annus {}: new moons do not cover both winter solstices",
+                    annus
+                )
+            }
+            SolarTermOrder { annus, index } => {
+                write!(f, "annus {}: solar term {} not increasing", annus, index)
+            }
+            MoonPhaseOrder { annus, index } => {
+                write!(f, "annus {}: moon phase {:?} not increasing", annus, index)
             }
-        });
-        unsafe {
-            DATA.binary_search_by_key(&annus, |an| an.annus)
-                .ok()
-                .map(|i| &DATA[i])
+            Gap { prev, next } => write!(f, "annus {} follows {}", next, prev),
+            SolsticeMismatch { annus } => write!(
+                f,
+                "annus {}: winter solstice differs from the end of the previous annus",
+                annus
+            ),
         }
     }
 }
@@ -54,9 +189,7 @@ fn parse_raw_data() -> Result<Vec<Annus>, RawDataError> {
                 .parse()
                 .map_err(|e| RawDataError::new(line_num, 1, ErrorType::InvalidInt(e)))?,
         };
-        // XXX dumtempe limita por pli rapida pravalorizado
-        // (Ankaŭ pro la ankoraŭ ne realigita UT-taksado antaŭ ol 1972)
-        if !(1973..=2050).contains(&annus) {
+        if !ANNUS_RANGE.contains(&annus) {
             continue;
         }
         let jd0 = require_next_f64(&mut it, line_num, 2)?;
@@ -160,10 +293,104 @@ mod tests {
         assert_eq!("2000-12-21", date.iso_gregorian());
     }
 
+    #[test]
+    fn validation() {
+        use ValidationIssue::*;
+        assert_eq!(Ok(()), validate());
+
+        let mut data = vec![Annus::get(2000).unwrap().clone()];
+        assert_eq!(Ok(()), validate_data(&data));
+        data.push(Annus::get(2002).unwrap().clone());
+        data.push(Annus::get(2003).unwrap().clone());
+        data[1].solar_term.swap(3, 4);
+        data[2].moon_phase[5][2] = Tdb(0.0);
+        data[2].solar_term[0].0 += 0.5;
+        let issues = validate_data(&data).unwrap_err();
+        assert_eq!(
+            vec![
+                SolarTermOrder {
+                    annus: 2002,
+                    index: 4
+                },
+                MoonPhaseOrder {
+                    annus: 2003,
+                    index: (5, 2)
+                },
+                Gap {
+                    prev: 2000,
+                    next: 2002
+                },
+                SolsticeMismatch { annus: 2003 },
+            ],
+            issues
+        );
+        assert_eq!(
+            "annus 2003: moon phase (5, 2) not increasing",
+            issues[1].to_string()
+        );
+
+        let mut annus = Annus::get(2000).unwrap().clone();
+        annus.solar_term[24] = annus.moon_phase[14][0];
+        let issues = validate_data(&[annus.clone()]).unwrap_err();
+        assert_eq!(vec![NewMoonCoverage { annus: 2000 }], issues);
+        annus.solar_term[24] = annus.moon_phase[11][1];
+        let issues = validate_data(&[annus]).unwrap_err();
+        assert_eq!(
+            vec![
+                SolarTermOrder {
+                    annus: 2000,
+                    index: 24
+                },
+                MonthCount {
+                    annus: 2000,
+                    months: 11
+                }
+            ],
+            issues
+        );
+    }
+
+    #[test]
+    fn concurrent_get() {
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    ANNUS_RANGE
+                        .map(|annus| (annus + i) % 151 + 1950)
+                        .map(|annus| (annus, Annus::get(annus).map(|an| an.annus)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            for (annus, res) in handle.join().unwrap() {
+                assert_eq!(Some(annus), res);
+            }
+        }
+        let first = Annus::get(2000).unwrap() as *const Annus;
+        let second = std::thread::spawn(|| Annus::get(2000).unwrap() as *const Annus as usize);
+        assert_eq!(first as usize, second.join().unwrap());
+        assert!(Annus::get(1949).is_none());
+        assert!(Annus::get(2101).is_none());
+    }
+
     #[test]
     fn new_moons() {
         let annus = Annus::get(2000).unwrap();
         let date = date_cst(annus.moon_phase[0][0]);
         assert_eq!("1999-12-08", date.iso_gregorian());
     }
+
+    #[test]
+    fn moon_phase_accessors() {
+        let annus = Annus::get(2000).unwrap();
+        assert_eq!(15, annus.moon_phases().count());
+        for (i, phases) in annus.moon_phases().enumerate() {
+            assert_eq!(annus.moon_phase[i], phases);
+            assert_eq!(Some(phases[0]), annus.nth_new_moon(i));
+        }
+        let date = date_cst(annus.nth_new_moon(0).unwrap());
+        assert_eq!("1999-12-08", date.iso_gregorian());
+        assert_eq!(None, annus.nth_new_moon(15));
+    }
 }
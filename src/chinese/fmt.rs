@@ -3,6 +3,69 @@
 /// 漢數字，第 `1..=9` 項分別為「一」到「九」。為便於格式化日期，第 0 項為「十」。
 pub const NUM_CHINESE: &[&str] = &["十", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
 
+/// 逐位寫出數字，如年份 2017 為「二〇一七」，負數前加「負」。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese;
+///
+/// assert_eq!("二〇一七", chinese::fmt::digits(2017));
+/// ```
+pub fn digits(num: i32) -> String {
+    const DIGITS: &[char] = &['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+    let mut rt = String::new();
+    if num < 0 {
+        rt += "負";
+    }
+    for b in num.unsigned_abs().to_string().bytes() {
+        rt.push(DIGITS[(b - b'0') as usize]);
+    }
+    rt
+}
+
+/// 取得數目的漢字寫法，如 25 為「二十五」、305 為「三百零五」，`10..=19` 省略「一」作「十」「十五」。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese;
+///
+/// assert_eq!("十二", chinese::fmt::number(12));
+/// assert_eq!("三百六十六", chinese::fmt::number(366));
+/// ```
+///
+/// # Panics
+///
+/// 若數目不在 `0..10000` 間則 panic。
+pub fn number(num: i32) -> String {
+    const UNITS: &[&str] = &["", "十", "百", "千"];
+    if !(0..10000).contains(&num) {
+        panic!("number {} not in 0..10000", num);
+    }
+    if num == 0 {
+        return "〇".to_owned();
+    }
+    let mut rt = String::new();
+    let mut zero = false;
+    for pos in (0..4).rev() {
+        let digit = num / 10i32.pow(pos) % 10;
+        if digit == 0 {
+            zero = !rt.is_empty();
+            continue;
+        }
+        if zero {
+            rt += "零";
+            zero = false;
+        }
+        if !(pos == 1 && digit == 1 && rt.is_empty()) {
+            rt += NUM_CHINESE[digit as usize];
+        }
+        rt += UNITS[pos as usize];
+    }
+    rt
+}
+
 /// 干支序號轉為文本形式。
 ///
 /// # 用例
@@ -20,6 +83,22 @@ pub fn sexagenary(num: i32) -> String {
     NAME1[num.rem_euclid(10) as usize].to_owned() + NAME2[num.rem_euclid(12) as usize]
 }
 
+/// 生肖序號轉為文本形式，`1..=12` 分別為鼠到豬，見 [`zodiac_for_year`](super::zodiac_for_year)。序號以 12 為周期循環。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese;
+///
+/// assert_eq!("龍", chinese::fmt::zodiac(5));
+/// ```
+pub fn zodiac(num: i32) -> &'static str {
+    static NAMES: &[&str] = &[
+        "豬", "鼠", "牛", "虎", "兔", "龍", "蛇", "馬", "羊", "猴", "雞", "狗",
+    ];
+    NAMES[num.rem_euclid(12) as usize]
+}
+
 /// 取得月名（含「月」字）。十一、十二月稱「冬月」「臘月」。
 ///
 /// # 用例
@@ -35,21 +114,58 @@ pub fn sexagenary(num: i32) -> String {
 ///
 /// 若月序號不在 `1..=12` 間則 panic。
 pub fn month(m: super::Month) -> String {
+    let num = m.num();
+    let name = month_num(num).unwrap_or_else(|| panic!("month {} not in 1..=12", num));
     let mut rt = String::new();
     if m.is_leap() {
         rt += "閏";
     }
-    let num = m.num();
-    rt += match num {
+    rt += name;
+    rt += "月";
+    rt
+}
+
+/// 閏月名的寫法，見 [`month_variant`]。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LeapStyle {
+    /// 與 [`month`] 相同，冠以「閏」字，如「閏六月」
+    Prefix,
+    /// 綴以「後」字，表示閏月在同序號的平月之後，如「六月後」
+    Suffix,
+}
+
+/// 同 [`month`]，但閏月可選用 `style` 指定的寫法；平月不受影響。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::{fmt::{month_variant, LeapStyle::*}, Month::*};
+///
+/// assert_eq!("閏六月", month_variant(Leap(6), Prefix));
+/// assert_eq!("六月後", month_variant(Leap(6), Suffix));
+/// assert_eq!("六月", month_variant(Common(6), Suffix));
+/// ```
+///
+/// # Panics
+///
+/// 若月序號不在 `1..=12` 間則 panic。
+pub fn month_variant(m: super::Month, style: LeapStyle) -> String {
+    match (m, style) {
+        (super::Month::Leap(num), LeapStyle::Suffix) => month(super::Month::Common(num)) + "後",
+        _ => month(m),
+    }
+}
+
+/// 月序號的文本形式（不含「閏」「月」字），不在 `1..=12` 間則返回 `None`。
+pub(crate) fn month_num(num: i32) -> Option<&'static str> {
+    Some(match num {
         1 => "正",
         2..=9 => NUM_CHINESE[num as usize],
         10 => "十",
         11 => "冬",
         12 => "臘",
-        _ => panic!("month {} not in 1..=12", num),
-    };
-    rt += "月";
-    rt
+        _ => return None,
+    })
 }
 
 /// 取得日名，前十日為「初一」到「初十」，第 21 至 29 日為「廿一」到「廿九」。
@@ -80,8 +196,46 @@ pub fn day(d: i32) -> String {
         + NUM_CHINESE[(d % 10) as usize]
 }
 
+const SOLAR_TERM_NAMES: &[&str] = &[
+    "大寒", "立春", "雨水", "驚蟄", "春分", "清明", "穀雨", "立夏", "小滿", "芒種", "夏至", "小暑",
+    "大暑", "立秋", "處暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至", "小寒",
+];
+
+/// 日名的寫法，見 [`day_variant`]。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DayGlyphStyle {
+    /// 與 [`day`] 相同，三十日作「三十」
+    Standard,
+    /// 三十日作「卅」，其餘同 `Standard`
+    Alternative,
+}
+
+/// 同 [`day`]，但可選用 `style` 指定的寫法。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::fmt::{day_variant, DayGlyphStyle::*};
+///
+/// assert_eq!("三十", day_variant(30, Standard));
+/// assert_eq!("卅", day_variant(30, Alternative));
+/// assert_eq!("廿五", day_variant(25, Alternative));
+/// ```
+///
+/// # Panics
+///
+/// 若日序號不在 `1..=30` 間則 panic。
+pub fn day_variant(d: i32, style: DayGlyphStyle) -> String {
+    match (d, style) {
+        (30, DayGlyphStyle::Alternative) => "卅".to_owned(),
+        _ => day(d),
+    }
+}
+
 /// 節氣序號轉為名稱。`1..=24` 分別為立春到大寒。
 ///
+/// 序號以 24 為周期循環，如 `0` 亦為大寒；須檢查範圍時用 [`solar_term_checked`]。
+///
 /// # 用例
 ///
 /// ```
@@ -90,12 +244,44 @@ pub fn day(d: i32) -> String {
 /// assert_eq!("穀雨", chinese::fmt::solar_term(6));
 /// ```
 pub fn solar_term(term: i32) -> &'static str {
-    const NAMES: &[&str] = &[
-        "大寒", "立春", "雨水", "驚蟄", "春分", "清明", "穀雨", "立夏", "小滿", "芒種", "夏至",
-        "小暑", "大暑", "立秋", "處暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪",
-        "冬至", "小寒",
-    ];
-    NAMES[term.rem_euclid(24) as usize]
+    SOLAR_TERM_NAMES[term.rem_euclid(24) as usize]
+}
+
+/// 同 [`solar_term`]，但序號不在 `1..=24` 間時返回 `None`，不循環。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::fmt::solar_term_checked;
+///
+/// assert_eq!(Some("大寒"), solar_term_checked(24));
+/// assert_eq!(None, solar_term_checked(0));
+/// ```
+pub fn solar_term_checked(term: i32) -> Option<&'static str> {
+    if (1..=24).contains(&term) {
+        Some(solar_term(term))
+    } else {
+        None
+    }
+}
+
+/// 取得 `date` 的完整夏曆日期寫法，年份逐位寫出，如「二〇一七年閏六月初一」。
+///
+/// 若曆表無該日所在歲資料則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::fmt::full_date;
+///
+/// let date = Date::from_gregorian(2017, 7, 23).unwrap();
+///
+/// assert_eq!(Some("二〇一七年閏六月初一".to_owned()), full_date(date));
+/// ```
+pub fn full_date(date: crate::date::Date) -> Option<String> {
+    let (y, m, d) = super::Annus::from_date(date)?.ymd_for(date).ok()?;
+    Some(digits(y) + "年" + &month(m) + &day(d))
 }
 
 #[cfg(test)]
@@ -109,6 +295,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_zodiac() {
+        for (std, num) in [
+            ("鼠", 1),
+            ("龍", 5),
+            ("雞", 10),
+            ("豬", 12),
+            ("豬", 0),
+            ("鼠", 13),
+        ] {
+            assert_eq!(std, zodiac(num));
+        }
+        for num in 1..=60 {
+            let branch = sexagenary(num).chars().nth(1).unwrap();
+            let std = sexagenary((num - 1) % 12 + 1).chars().nth(1).unwrap();
+            assert_eq!(std, branch);
+        }
+    }
+
+    #[test]
+    fn test_number() {
+        for (std, num) in [
+            ("〇", 0),
+            ("七", 7),
+            ("十", 10),
+            ("十五", 15),
+            ("二十", 20),
+            ("一百", 100),
+            ("一百一十", 110),
+            ("三百零五", 305),
+            ("一千零一", 1001),
+            ("九千九百九十九", 9999),
+        ] {
+            assert_eq!(std, number(num));
+        }
+        for (std, num) in [("〇", 0), ("二〇〇〇", 2000), ("負四四", -44)] {
+            assert_eq!(std, digits(num));
+        }
+    }
+
+    #[test]
+    fn test_month_variant() {
+        use super::super::Month::*;
+        assert_eq!("閏六月", month_variant(Leap(6), LeapStyle::Prefix));
+        assert_eq!("六月後", month_variant(Leap(6), LeapStyle::Suffix));
+        assert_eq!("冬月後", month_variant(Leap(11), LeapStyle::Suffix));
+        for num in 1..=12 {
+            for style in [LeapStyle::Prefix, LeapStyle::Suffix] {
+                assert_eq!(month(Common(num)), month_variant(Common(num), style));
+            }
+            assert_eq!(
+                month(Leap(num)),
+                month_variant(Leap(num), LeapStyle::Prefix)
+            );
+        }
+    }
+
     #[test]
     fn test_day() {
         for (std, d) in [
@@ -122,4 +365,45 @@ mod tests {
             assert_eq!(std, day(d));
         }
     }
+
+    #[test]
+    fn test_day_variant() {
+        for d in 1..=29 {
+            assert_eq!(day(d), day_variant(d, DayGlyphStyle::Alternative));
+        }
+        for d in 1..=30 {
+            assert_eq!(day(d), day_variant(d, DayGlyphStyle::Standard));
+        }
+        assert_eq!("卅", day_variant(30, DayGlyphStyle::Alternative));
+        assert_eq!("廿五", day_variant(25, DayGlyphStyle::Alternative));
+        assert_eq!("二十", day_variant(20, DayGlyphStyle::Alternative));
+    }
+
+    #[test]
+    fn test_full_date() {
+        use crate::date::Date;
+        for ((y, m, d), std) in [
+            ((2017, 7, 23), "二〇一七年閏六月初一"),
+            ((2017, 1, 27), "二〇一六年臘月三十"),
+            ((2017, 1, 28), "二〇一七年正月初一"),
+            ((2000, 1, 1), "一九九九年冬月廿五"),
+            ((2020, 1, 25), "二〇二〇年正月初一"),
+        ] {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(Some(std.to_owned()), full_date(date), "{:?}", (y, m, d));
+        }
+        assert_eq!(None, full_date(Date::from_gregorian(1900, 1, 1).unwrap()));
+        assert_eq!(None, full_date(Date::MAX));
+    }
+
+    #[test]
+    fn test_solar_term() {
+        for term in 1..=24 {
+            assert_eq!(Some(solar_term(term)), solar_term_checked(term));
+        }
+        for term in [0, 25, -1, 100] {
+            assert_eq!(None, solar_term_checked(term));
+        }
+        assert_eq!(Some("立春"), solar_term_checked(1));
+    }
 }
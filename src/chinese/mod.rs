@@ -4,8 +4,10 @@
 //!
 //! 本程序採用預製好的天文曆表資料編算夏曆，見 [`ephemeris`]。
 
+use std::collections::BTreeMap;
+
 use crate::date::Date;
-use crate::time_scales::{Tdb, Ut};
+use crate::time_scales::{DateTime, Tdb, Ut};
 
 pub mod ephemeris;
 pub mod fmt;
@@ -35,9 +37,24 @@ pub struct Annus {
     pub ephemeris: &'static ephemeris::Annus,
     /// 全部月首，包括次一歲首月用以標記本歲最末日
     pub months: Vec<NewMoon>,
+    /// 建構時由 `ephemeris.solar_term` 換算的各交節日期，免去重複換算；僅與當時的曆表對應，其後改動 `ephemeris` 不會隨之更新
+    term_dates: [Date; 25],
+}
+/// 歲由其序號唯一確定，故僅比較 `annus`。
+impl PartialEq for Annus {
+    fn eq(&self, other: &Self) -> bool {
+        self.annus == other.annus
+    }
+}
+impl Eq for Annus {}
+impl std::hash::Hash for Annus {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.annus.hash(state);
+    }
 }
 /// 月首信息
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NewMoon {
     /// 月名
     pub month: Month,
@@ -46,6 +63,7 @@ pub struct NewMoon {
 }
 /// 月名，`Common` 為平月，`Leap` 為閏月。
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Month {
     Common(i32),
     Leap(i32),
@@ -66,6 +84,81 @@ impl Month {
     pub fn name(&self) -> String {
         fmt::month(*self)
     }
+    /// 自月名解析，為 [`name`](Self::name) 的逆操作，亦接受「一月」「十一月」「十二月」等寫法。無法識別時返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Month::{self, *};
+    ///
+    /// assert_eq!(Some(Leap(5)), Month::from_str_cjk("閏五月"));
+    /// assert_eq!(Some(Common(12)), Month::from_str_cjk("臘月"));
+    /// assert_eq!(Some(Common(12)), Month::from_str_cjk("十二月"));
+    /// assert_eq!(None, Month::from_str_cjk("十三月"));
+    /// ```
+    pub fn from_str_cjk(s: &str) -> Option<Self> {
+        let body = s.strip_suffix('月')?;
+        let (leap, body) = match body.strip_prefix('閏') {
+            Some(body) => (true, body),
+            None => (false, body),
+        };
+        let num = match body {
+            "一" => 1,
+            "十一" => 11,
+            "十二" => 12,
+            _ => (1..=12).find(|&num| fmt::month_num(num) == Some(body))?,
+        };
+        Some(if leap {
+            Month::Leap(num)
+        } else {
+            Month::Common(num)
+        })
+    }
+}
+/// 同 [`Month::name`]，但月序號不在 `1..=12` 間時不 panic，改以阿拉伯數字輸出（如「閏13月」）。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::Month::*;
+///
+/// assert_eq!("閏正月", format!("{}", Leap(1)));
+/// assert_eq!("13月", format!("{}", Common(13)));
+/// ```
+impl std::fmt::Display for Month {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_leap() {
+            f.write_str("閏")?;
+        }
+        match fmt::month_num(self.num()) {
+            Some(name) => write!(f, "{}月", name),
+            None => write!(f, "{}月", self.num()),
+        }
+    }
+}
+
+/// 依月序號排序，閏月緊隨同序號的平月之後。
+///
+/// 僅比較月名，不考慮跨年：十一、十二月仍排在正月之前，在歲中的先後應以月首日期判斷。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::Month::*;
+///
+/// assert!(Common(6) < Leap(6) && Leap(6) < Common(7));
+/// assert!(Common(1) < Common(11));
+/// ```
+impl Ord for Month {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.num(), self.is_leap()).cmp(&(other.num(), other.is_leap()))
+    }
+}
+
+impl PartialOrd for Month {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl Annus {
@@ -81,29 +174,52 @@ impl Annus {
     /// let annus = Annus::new(2000).unwrap();
     /// ```
     pub fn new(annus: i32) -> Option<Self> {
+        Some(Self::from_ephemeris(ephemeris::Annus::get(annus)?))
+    }
+    /// 依已取得的曆表建立歲，免去重複查找。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::{ephemeris, Annus};
+    ///
+    /// let annus = Annus::from_ephemeris(ephemeris::Annus::get(2000).unwrap());
+    ///
+    /// assert_eq!(2000, annus.annus);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// 曆表中兩冬至間並非十二或十三個月時 panic。
+    pub fn from_ephemeris(ephemeris: &'static ephemeris::Annus) -> Self {
         use Month::*;
 
-        let ephemeris = ephemeris::Annus::get(annus)?;
+        let annus = ephemeris.annus;
         let new_moon_dates: Vec<_> = ephemeris
             .moon_phase
             .iter()
             .map(|arr| date_cst(arr[0]))
             .collect();
-        let ws = date_cst(ephemeris.solar_term[0]);
-        let ws_next = date_cst(ephemeris.solar_term[24]);
+        let mut term_dates = [Date::from_jdn(0); 25];
+        for (date, &tdb) in term_dates.iter_mut().zip(&ephemeris.solar_term) {
+            *date = date_cst(tdb);
+        }
+        let ws = term_dates[0];
+        let ws_next = term_dates[24];
         let m11_idx = new_moon_dates.partition_point(|date| date <= &ws) - 1;
-        let m11n_idx = new_moon_dates.partition_point(|date| date < &ws_next) - 1;
-        let mut needs_leap = match m11n_idx - m11_idx {
+        let m11n_idx = new_moon_dates.partition_point(|date| date <= &ws_next) - 1;
+        let leap = match m11n_idx - m11_idx {
             12 => false,
             13 => true,
             _ => panic!("{} months between winter solstices", m11n_idx - m11_idx),
         };
+        let mut needs_leap = leap;
 
         let mut months = Vec::with_capacity(m11n_idx - m11_idx);
         let mut month = 10;
         let mut term = 0;
         for i in m11_idx..=m11n_idx {
-            if needs_leap && new_moon_dates[i + 1] <= date_cst(ephemeris.solar_term[term]) {
+            if needs_leap && new_moon_dates[i + 1] <= term_dates[term] {
                 months.push(NewMoon {
                     month: Leap(month),
                     date: new_moon_dates[i],
@@ -120,11 +236,12 @@ impl Annus {
         }
         assert!(!needs_leap);
 
-        Some(Annus {
+        Annus {
             annus,
             ephemeris,
             months,
-        })
+            term_dates,
+        }
     }
     /// 依特定日期取得其所在歲。
     ///
@@ -157,6 +274,129 @@ impl Annus {
         }
     }
 
+    /// 取得以給定日期為起始冬至的歲，即公元 `date` 年冬至起算的次一歲。
+    ///
+    /// 若曆表無該歲資料，或給定日期並非冬至，則返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let date = Date::from_gregorian(1999, 12, 22).unwrap();
+    /// let annus = Annus::from_winter_solstice(date).unwrap();
+    ///
+    /// assert_eq!(2000, annus.annus);
+    /// ```
+    pub fn from_winter_solstice(date: Date) -> Option<Self> {
+        let annus = Self::new(date.gregorian().0 + 1)?;
+        if annus.term_dates[0] == date {
+            Some(annus)
+        } else {
+            None
+        }
+    }
+
+    /// 該歲是否有閏月（即兩冬至間有十三個月）。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// assert!(!Annus::new(2000).unwrap().is_leap_year());
+    /// assert!(Annus::new(2017).unwrap().is_leap_year());
+    /// ```
+    pub fn is_leap_year(&self) -> bool {
+        // 十三個月另加次一歲首月
+        self.months.len() == 14
+    }
+
+    /// 本歲第 `idx` 月（即 `months[idx]`）是否含中氣。冬至間有十三個月時，首個無中氣之月即為閏月。
+    ///
+    /// `idx` 超出本歲各月，或為標記歲末的次一歲首月時，返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::{Annus, Month::*};
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    ///
+    /// assert_eq!(Leap(6), annus.months[8].month);
+    /// assert_eq!(Some(false), annus.month_has_zhongqi(8));
+    /// assert_eq!(Some(true), annus.month_has_zhongqi(7));
+    /// assert_eq!(None, annus.month_has_zhongqi(13));
+    /// ```
+    pub fn month_has_zhongqi(&self, idx: usize) -> Option<bool> {
+        let start = self.months.get(idx)?.date;
+        let end = self.months.get(idx + 1)?.date;
+        Some(
+            self.term_dates
+                .iter()
+                .step_by(2)
+                .any(|date| (start..end).contains(date)),
+        )
+    }
+
+    /// 本歲是否同時有與 `month` 同序號的平月與閏月，即月序號重複、須標出「閏」字以區分。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::{Annus, Month::*};
+    ///
+    /// let annus = Annus::new(2017).unwrap(); // 閏六月
+    ///
+    /// assert!(annus.is_leap_context(Common(6)));
+    /// assert!(annus.is_leap_context(Leap(6)));
+    /// assert!(!annus.is_leap_context(Common(5)));
+    /// ```
+    pub fn is_leap_context(&self, month: Month) -> bool {
+        let months = &self.months[..self.months.len() - 1];
+        let num = month.num();
+        months.iter().any(|m| m.month == Month::Leap(num))
+            && months.iter().any(|m| m.month == Month::Common(num))
+    }
+
+    /// 依次取得本歲各月的月首信息及其日數，大月 30 日，小月 29 日；末月以次歲首月月首為界。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::{Annus, Month::*};
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    /// let lengths: Vec<_> = annus.month_lengths().map(|(m, len)| (m.month, len)).collect();
+    ///
+    /// assert_eq!(13, lengths.len());
+    /// assert_eq!((Common(11), 30), lengths[0]);
+    /// ```
+    pub fn month_lengths(&self) -> impl Iterator<Item = (NewMoon, i32)> + '_ {
+        self.months
+            .windows(2)
+            .map(|pair| (pair[0], pair[1].date - pair[0].date))
+    }
+    /// 取得本歲 `month` 月的日數，大月 30 日，小月 29 日。本歲無該月時返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::{Annus, Month::*};
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    ///
+    /// assert_eq!(Some(29), annus.month_length(Common(6)));
+    /// assert_eq!(Some(30), annus.month_length(Leap(6)));
+    /// assert_eq!(None, annus.month_length(Leap(5)));
+    /// ```
+    pub fn month_length(&self, month: Month) -> Option<i32> {
+        self.month_lengths()
+            .find(|(m, _)| m.month == month)
+            .map(|(_, len)| len)
+    }
+
     /// 取得給定日期在該歲的年月日，返回格式為 `(年, 月, 日)`。
     ///
     /// 若所給日期不在該歲，則回報 `Err` 並指出該日期在該歲之前還是之後。
@@ -173,6 +413,36 @@ impl Annus {
     /// assert_eq!(Ok((1999, Common(11), 25)), annus.ymd_for(date));
     /// ```
     pub fn ymd_for(&self, date: Date) -> Result<(i32, Month, i32), OtherAnnus> {
+        self.ymd_for_with_epoch(date, 1)
+    }
+    /// 同 [`ymd_for`](Self::ymd_for)，但以 `year_start_month` 月為歲首（如夏曆建寅為 `1`，殷曆建丑為 `12`，周曆建子為 `11`），據此決定所在年份。月名不變。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::{Annus, Month::*};
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// let annus = Annus::from_date(date).unwrap();
+    ///
+    /// assert_eq!(Ok((1999, Common(11), 25)), annus.ymd_for_with_epoch(date, 1));
+    /// assert_eq!(Ok((2000, Common(11), 25)), annus.ymd_for_with_epoch(date, 11));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `year_start_month` 不在 `1..=12` 間時 panic。
+    pub fn ymd_for_with_epoch(
+        &self,
+        date: Date,
+        year_start_month: i32,
+    ) -> Result<(i32, Month, i32), OtherAnnus> {
+        assert!(
+            (1..=12).contains(&year_start_month),
+            "month {} not in 1..=12",
+            year_start_month
+        );
         let begin = self.months[0].date;
         let end = self.months.last().unwrap().date;
 
@@ -188,13 +458,125 @@ impl Annus {
             .take_while(|m| m.date <= date)
             .last()
             .unwrap();
+        Ok(self.ymd_in_month(m, date, year_start_month))
+    }
+    /// 同 [`ymd_for`](Self::ymd_for)，但一次處理多個日期，返回與 `dates` 一一對應的結果。
+    ///
+    /// `dates` 依升序排列時，逐月推進而不必每日重新查找所在月，適合批量編排日曆；未排序亦可得正確結果，僅較慢。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::{Annus, Month::*, OtherAnnus};
+    ///
+    /// let annus = Annus::new(2000).unwrap();
+    /// let start = Date::from_gregorian(1999, 12, 7).unwrap();
+    /// let dates: Vec<_> = (0..3).map(|i| start + i).collect();
+    ///
+    /// assert_eq!(
+    ///     vec![Err(OtherAnnus::Before), Ok((1999, Common(11), 1)), Ok((1999, Common(11), 2))],
+    ///     annus.label_sorted(&dates),
+    /// );
+    /// ```
+    pub fn label_sorted(&self, dates: &[Date]) -> Vec<Result<(i32, Month, i32), OtherAnnus>> {
+        let begin = self.months[0].date;
+        let end = self.months.last().unwrap().date;
+        let mut idx = 0;
+        dates
+            .iter()
+            .map(|&date| {
+                if date < begin {
+                    return Err(OtherAnnus::Before);
+                } else if date >= end {
+                    return Err(OtherAnnus::After);
+                }
+                if date < self.months[idx].date {
+                    idx = 0;
+                }
+                while self.months[idx + 1].date <= date {
+                    idx += 1;
+                }
+                Ok(self.ymd_in_month(&self.months[idx], date, 1))
+            })
+            .collect()
+    }
+    /// 依次取得本歲每一日及其年月日，格式為 `(日期, 年, 月, 日)`，自首月初一至次一歲首月前一日。
+    ///
+    /// 逐月推進，較對每日調用 [`ymd_for`](Self::ymd_for) 快，適合編排整歲日曆。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::{Annus, Month::*};
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    /// let (date, y, m, d) = annus.days().nth(60).unwrap();
+    ///
+    /// assert_eq!((2017, 1, 28), date.gregorian());
+    /// assert_eq!((2017, Common(1), 1), (y, m, d));
+    /// ```
+    pub fn days(&self) -> impl Iterator<Item = (Date, i32, Month, i32)> + '_ {
+        self.months.windows(2).flat_map(move |pair| {
+            let (this, next) = (pair[0], pair[1]);
+            let (y, m, _) = self.ymd_in_month(&this, this.date, 1);
+            (1..=next.date - this.date).map(move |d| (this.date + (d - 1), y, m, d))
+        })
+    }
+    /// 給定日期所在月 `m`，計算其年月日。
+    fn ymd_in_month(&self, m: &NewMoon, date: Date, year_start_month: i32) -> (i32, Month, i32) {
         let d = (date.jdn() + 1 - m.date.jdn()) as i32;
-        let y = if m.month.num() >= 11 {
+        // 歲內各月依十一月、十二月、正月……十月排列，歲首之前者屬上一年
+        let pos = |num: i32| (num - 11).rem_euclid(12);
+        let y = if pos(m.month.num()) < pos(year_start_month) {
             self.annus - 1
         } else {
             self.annus
         };
-        Ok((y, m.month, d))
+        (y, m.month, d)
+    }
+    /// [`ymd_for`](Self::ymd_for) 之逆，取得本歲內 `year` 年 `month` 月 `day` 日的日期。
+    ///
+    /// 若該月不在本歲，返回 `Err(LunarDateErr::OtherAnnus(_))` 並指出其在前還是在後；若本歲並無該月（如無此閏月），返回 `Err(LunarDateErr::NoSuchMonth)`；若該月並無 `day` 日（如小月三十），返回 `Err(LunarDateErr::NoSuchDay)`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::{Annus, LunarDateErr, Month::*};
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    ///
+    /// let date = annus.date_for(2017, Leap(6), 1).unwrap();
+    ///
+    /// assert_eq!((2017, 7, 23), date.gregorian());
+    /// assert_eq!(Err(LunarDateErr::NoSuchMonth), annus.date_for(2017, Leap(5), 1));
+    /// assert_eq!(Err(LunarDateErr::NoSuchDay), annus.date_for(2017, Common(6), 30));
+    /// ```
+    pub fn date_for(&self, year: i32, month: Month, day: i32) -> Result<Date, LunarDateErr> {
+        let pair = self.months.windows(2).find(|pair| {
+            pair[0].month == month && self.ymd_in_month(&pair[0], pair[0].date, 1).0 == year
+        });
+        let (this, next) = match pair {
+            Some(pair) => (pair[0], pair[1]),
+            None => {
+                if !(1..=12).contains(&month.num()) {
+                    return Err(LunarDateErr::NoSuchMonth);
+                }
+                // 十一月、十二月屬次一年的歲
+                let annus = i64::from(year) + (month.num() >= 11) as i64;
+                return Err(match annus.cmp(&self.annus.into()) {
+                    std::cmp::Ordering::Less => LunarDateErr::OtherAnnus(OtherAnnus::Before),
+                    std::cmp::Ordering::Greater => LunarDateErr::OtherAnnus(OtherAnnus::After),
+                    std::cmp::Ordering::Equal => LunarDateErr::NoSuchMonth,
+                });
+            }
+        };
+        if !(1..=next.date - this.date).contains(&day) {
+            return Err(LunarDateErr::NoSuchDay);
+        }
+        Ok(this.date + (day - 1))
     }
 
     /// 取得給定日期所在節氣信息，若當日並無交節，則給出該日相對其前一個交節的日數差。返回值格式如下：
@@ -223,10 +605,10 @@ impl Annus {
         use SolarTermErr::*;
         if date < self.months[0].date {
             return Err(OtherAnnus(Before));
-        } else if date >= date_cst(self.ephemeris.solar_term[24]) {
+        } else if date >= self.term_dates[24] {
             return Err(OtherAnnus(After));
         }
-        if date < date_cst(self.ephemeris.solar_term[0]) {
+        if date < self.term_dates[0] {
             let last_annus = ephemeris::Annus::get(self.annus - 1).ok_or(NoData)?;
             for (idx, &tdb) in (22..24).zip(&last_annus.solar_term[22..24]).rev() {
                 let term_start = date_cst(tdb);
@@ -236,79 +618,1278 @@ impl Annus {
             }
             panic!("incorrect data for annus {}", self.annus - 1);
         }
-        let idx = self.ephemeris.solar_term[..24].partition_point(|&tdb| date_cst(tdb) <= date) - 1;
-        let off = date - date_cst(self.ephemeris.solar_term[idx]);
+        let idx = self.term_dates[..24].partition_point(|&term_date| term_date <= date) - 1;
+        let off = date - self.term_dates[idx];
         Ok((self.annus, (idx as i32 + 21) % 24 + 1, off))
     }
-}
-
-/// 表示給定日期不在該歲，並指出其在前還是在後。
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub enum OtherAnnus {
-    Before,
-    After,
-}
-
-/// 表示給定日期不在該歲，或曆表無法取得節氣數據。
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub enum SolarTermErr {
-    NoData,
-    OtherAnnus(OtherAnnus),
-}
 
-/// 將給定曆表時間轉為北京時間（UTC+8）日期。
-pub fn date_cst(tdb: Tdb) -> Date {
-    Ut::convert(tdb).date_in_timezone(480)
-}
-
-/// 取得所給公元年的干支。
-///
-/// # 用例
-///
-/// ```
-/// use kalendarilo::chinese::sexagenary_for_year;
-///
-/// assert_eq!(1, sexagenary_for_year(-2696));
-/// ```
-pub fn sexagenary_for_year(year: i32) -> i32 {
-    (year + 2696).rem_euclid(60) + 1
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn from_date() {
-        let dataset = [
-            (2017, (2017, 1, 27)),
-            (2017, (2017, 12, 17)),
-            (2018, (2017, 12, 18)),
-        ];
-        for (std, (y, m, d)) in dataset {
-            assert_eq!(
-                Some(std),
-                Annus::from_date(Date::from_gregorian(y, m, d).unwrap()).map(|a| a.annus)
-            );
-        }
+    /// 估算給定日期的太陽黃經（單位為度，`0.0..360.0`）。
+    ///
+    /// 各節氣交節時刻相隔黃經 15°，本方法以交節日為準，在前後兩交節日間按日數線性插值，故交節當日恰為 15° 的整數倍。
+    /// 實際太陽視運動並不均勻，且交節時刻未必在日首，所得結果僅為近似值（誤差可達約半度），宜作展示用途。
+    ///
+    /// 支持範圍與 [`solar_term_for`](Self::solar_term_for) 相同，錯誤情形亦同。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let date = Date::from_gregorian(1999, 12, 22).unwrap();
+    /// let annus = Annus::from_date(date).unwrap();
+    ///
+    /// assert_eq!(Ok(270.0), annus.solar_longitude_approx(date)); // 冬至
+    /// ```
+    pub fn solar_longitude_approx(&self, date: Date) -> Result<f64, SolarTermErr> {
+        let (annus, term, off) = self.solar_term_for(date)?;
+        let idx = term_index(term);
+        let len = if annus == self.annus {
+            self.term_dates[idx + 1] - self.term_dates[idx]
+        } else {
+            let solar_term = &ephemeris::Annus::get(annus)
+                .ok_or(SolarTermErr::NoData)?
+                .solar_term;
+            date_cst(solar_term[idx + 1]) - date_cst(solar_term[idx])
+        };
+        let lon = (270 + 15 * idx) as f64 + 15.0 * off as f64 / len as f64;
+        Ok(lon % 360.0)
     }
 
-    #[test]
-    fn months() {
-        let annus = Annus::new(2000).unwrap();
-        let stds = [
-            (11, "1999-12-08"),
-            (12, "2000-01-07"),
-            (1, "2000-02-05"),
-            (2, "2000-03-06"),
-            (3, "2000-04-05"),
-            (4, "2000-05-04"),
-            (5, "2000-06-02"),
-            (6, "2000-07-02"),
-            (7, "2000-07-31"),
-            (8, "2000-08-29"),
-            (9, "2000-09-28"),
-            (10, "2000-10-27"),
+    /// 取得給定日期之後的下一個交節，與 [`solar_term_for`](Self::solar_term_for) 對應。返回值格式如下：
+    ///
+    /// - `.0`：下一節氣所在歲（自大雪之後為次一歲冬至）
+    /// - `.1`：下一節氣序號，1..=24 對應立春到大寒
+    /// - `.2`：距下一交節的日數，至少為 1
+    ///
+    /// 支持的區間及錯誤與 [`solar_term_for`](Self::solar_term_for) 相同。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// let annus = Annus::from_date(date).unwrap();
+    ///
+    /// assert_eq!(Ok((2000, 23, 5)), annus.next_solar_term(date)); // 距小寒 5 天
+    /// ```
+    pub fn next_solar_term(&self, date: Date) -> Result<(i32, i32, i32), SolarTermErr> {
+        let (annus, term, _) = self.solar_term_for(date)?;
+        let next_idx = term_index(term) + 1;
+        let next_term = term % 24 + 1;
+        let (next_annus, next_date) = if annus == self.annus {
+            if next_idx == 24 {
+                (annus + 1, self.term_dates[24])
+            } else {
+                (annus, self.term_dates[next_idx])
+            }
+        } else if next_idx == 24 {
+            (self.annus, self.term_dates[0])
+        } else {
+            let last_annus = ephemeris::Annus::get(annus).ok_or(SolarTermErr::NoData)?;
+            (annus, date_cst(last_annus.solar_term[next_idx]))
+        };
+        Ok((next_annus, next_term, next_date - date))
+    }
+    /// 取得 `date` 之後（不含當日）的首個交節，格式為 `(節氣序號, 交節日期)`，序號 `1..=24` 對應立春到大寒。
+    ///
+    /// 所求節氣可落在前後兩歲，如歲末大雪之後可取得屬次一歲的冬至。
+    /// `date` 須在本歲之內，否則或曆表缺少所需的相鄰歲數據時，返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    /// let date = Date::from_gregorian(2017, 12, 7).unwrap(); // 大雪
+    /// let (term, term_date) = annus.solar_term_after(date).unwrap();
+    ///
+    /// assert_eq!(22, term); // 冬至
+    /// assert_eq!((2017, 12, 22), term_date.gregorian());
+    /// ```
+    pub fn solar_term_after(&self, date: Date) -> Option<(i32, Date)> {
+        let terms = self.nearby_solar_terms(date)?;
+        terms.into_iter().find(|&(_, term_date)| term_date > date)
+    }
+    /// 取得 `date` 之前（不含當日）的最近交節，格式及支持範圍同 [`solar_term_after`](Self::solar_term_after)。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    /// let date = Date::from_gregorian(2016, 12, 21).unwrap(); // 冬至
+    /// let (term, term_date) = annus.solar_term_before(date).unwrap();
+    ///
+    /// assert_eq!(21, term); // 大雪
+    /// assert_eq!((2016, 12, 7), term_date.gregorian());
+    /// ```
+    pub fn solar_term_before(&self, date: Date) -> Option<(i32, Date)> {
+        let terms = self.nearby_solar_terms(date)?;
+        terms
+            .into_iter()
+            .rev()
+            .find(|&(_, term_date)| term_date < date)
+    }
+    /// 前一歲、本歲、次一歲中可取得的全部交節，依日期排列；`date` 不在本歲時返回 `None`。
+    fn nearby_solar_terms(&self, date: Date) -> Option<Vec<(i32, Date)>> {
+        if date < self.months[0].date || date >= self.months.last()?.date {
+            return None;
+        }
+        let mut res: Vec<(i32, Date)> = Vec::new();
+        for annus in self.annus - 1..=self.annus + 1 {
+            let ephemeris = match ephemeris::Annus::get(annus) {
+                Some(e) => e,
+                None => continue,
+            };
+            for (idx, &tdb) in (0..25).zip(&ephemeris.solar_term) {
+                let term_date = date_cst(tdb);
+                // 各歲首尾冬至重複
+                if res.last().map_or(true, |&(_, last)| term_date > last) {
+                    res.push(((idx + 21) % 24 + 1, term_date));
+                }
+            }
+        }
+        Some(res)
+    }
+    /// 取得本歲節氣 `term`（`1..=24`，立春到大寒）交節日所在的月，可用以說明何月無中氣而置閏。
+    ///
+    /// 大雪等歲末節氣可能落在次一歲首月，此時返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::{Annus, Month::*};
+    ///
+    /// let annus = Annus::new(2017).unwrap();
+    ///
+    /// assert_eq!(Some(Common(11)), annus.solar_term_month(22)); // 冬至
+    /// assert_eq!(Some(Common(6)), annus.solar_term_month(12)); // 大暑
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `term` 不在 `1..=24` 間時 panic。
+    pub fn solar_term_month(&self, term: i32) -> Option<Month> {
+        assert!(
+            (1..=24).contains(&term),
+            "solar term {} not in 1..=24",
+            term
+        );
+        let date = self.term_dates[term_index(term)];
+        self.ymd_for(date).ok().map(|(_, month, _)| month)
+    }
+    /// 取得本歲內節氣 `term`（`1..=24`，立春到大寒）的交節日期，為 [`solar_term_for`](Self::solar_term_for) 之逆。
+    ///
+    /// 同 [`solar_term_month`](Self::solar_term_month)，大雪等歲末節氣可能落在次一歲首月，此時返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let annus = Annus::new(2024).unwrap();
+    /// let date = annus.solar_term_date(5).unwrap(); // 清明
+    ///
+    /// assert_eq!((2024, 4, 4), date.gregorian());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `term` 不在 `1..=24` 間時 panic。
+    pub fn solar_term_date(&self, term: i32) -> Option<Date> {
+        assert!(
+            (1..=24).contains(&term),
+            "solar term {} not in 1..=24",
+            term
+        );
+        let date = self.term_dates[term_index(term)];
+        Some(date).filter(|&date| date < self.months.last().unwrap().date)
+    }
+    /// 取得給定日期所在節氣的詳細信息，數值部分與 [`solar_term_for`](Self::solar_term_for) 相同，另含交節時刻及其北京時間。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::{Annus, SolarTerm};
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// let annus = Annus::from_date(date).unwrap();
+    /// let info = annus.solar_term_detail(date).unwrap();
+    ///
+    /// assert_eq!((SolarTerm::DongZhi, 2000, 10), (info.term, info.annus, info.days));
+    /// assert!(info.elapsed > 9.0 && info.elapsed < 10.0);
+    /// assert_eq!("1999-12-22", info.ingress_cst.date.iso_gregorian());
+    /// ```
+    pub fn solar_term_detail(&self, date: Date) -> Result<SolarTermInfo, SolarTermErr> {
+        let (annus, term, days) = self.solar_term_for(date)?;
+        let ephemeris = if annus == self.annus {
+            self.ephemeris
+        } else {
+            ephemeris::Annus::get(annus).ok_or(SolarTermErr::NoData)?
+        };
+        let ingress = ephemeris.solar_term[term_index(term)];
+        let ut = Ut::convert(ingress);
+        let (ingress_date, secs) = ut.local_day(CST_OFFSET_MINUTES);
+        Ok(SolarTermInfo {
+            term: SolarTerm::from_num(term).unwrap(),
+            annus,
+            days,
+            elapsed: date.days_since(ingress_date) as f64 - secs / 86400.0,
+            ingress,
+            ingress_cst: ut.datetime_in_timezone(CST_OFFSET_MINUTES),
+        })
+    }
+    /// 取得本歲內星期為 `weekday`（ISO-8601 星期序號，`1..=7` 為星期一至星期日）的全部日期，依日期排列。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let annus = Annus::new(2000).unwrap();
+    /// let sundays = annus.weekday_dates(7);
+    ///
+    /// assert_eq!(50, sundays.len());
+    /// assert_eq!("1999-12-12", sundays[0].iso_gregorian());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `weekday` 不在 `1..=7` 間時 panic。
+    pub fn weekday_dates(&self, weekday: i32) -> Vec<Date> {
+        let end = self.months.last().unwrap().date;
+        let mut date = self.months[0].date.weekday_on_or_after(weekday);
+        let mut res = Vec::new();
+        while date < end {
+            res.push(date);
+            date = date + 7;
+        }
+        res
+    }
+    /// 取得本歲起始冬至為冬月第幾日。冬至必在冬月，故不必返回月份。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// assert_eq!(15, Annus::new(2000).unwrap().winter_solstice_lunar_day()); // 1999-12-22
+    /// ```
+    pub fn winter_solstice_lunar_day(&self) -> i32 {
+        match self.ymd_for(self.term_dates[0]) {
+            Ok((_, Month::Common(11), day)) => day,
+            res => panic!(
+                "winter solstice not in month 11 of annus {}: {:?}",
+                self.annus, res
+            ),
+        }
+    }
+    /// 取得本歲首日的干支日序號（`1..=60`，甲子到癸亥）。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// assert_eq!(31, Annus::new(2000).unwrap().first_day_sexagenary()); // 甲午
+    /// ```
+    pub fn first_day_sexagenary(&self) -> i32 {
+        self.months[0].date.sexagenary()
+    }
+    /// 取得本歲內首個干支日序號為 `num`（`1..=60`）的日期。一歲必長於六十日，故總能找到。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let date = Annus::new(2000).unwrap().first_sexagenary_day(1); // 甲子
+    ///
+    /// assert_eq!("2000-01-07", date.iso_gregorian());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `num` 不在 `1..=60` 間時 panic。
+    pub fn first_sexagenary_day(&self, num: i32) -> Date {
+        assert!((1..=60).contains(&num), "sexagenary {} not in 1..=60", num);
+        let start = self.months[0].date;
+        start + sexagenary_between(start.sexagenary(), num)
+    }
+    /// 依次取得曆表所列全部月相及其北京時間日期，格式為 `(月相, 日期)`，月相 `0..=3` 分別為朔、上弦、望、下弦，同 [`ephemeris::Annus::moon_phase`]。
+    ///
+    /// 自冬至前一朔起共十五個月，故涵蓋本歲首月之前數日，及次一歲首數月。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let annus = Annus::new(2000).unwrap();
+    /// let (phase, date) = annus.moon_phases().next().unwrap();
+    ///
+    /// assert_eq!((0, "1999-12-08".to_owned()), (phase, date.iso_gregorian()));
+    /// assert_eq!(60, annus.moon_phases().count());
+    /// ```
+    pub fn moon_phases(&self) -> impl Iterator<Item = (i32, Date)> + '_ {
+        self.ephemeris
+            .moon_phases()
+            .flat_map(|phases| (0..).zip(phases.into_iter().map(date_cst)))
+    }
+    /// 取得 `date` 當日或之前最近的月相，格式同 [`moon_phases`](Self::moon_phases)。
+    ///
+    /// 若 `date` 早於曆表所列首個月相，或晚於末個月相，則無法確定，返回 `None`。
+    ///
+    /// # 用例
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    /// use kalendarilo::chinese::Annus;
+    ///
+    /// let annus = Annus::new(2000).unwrap();
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// let (phase, phase_date) = annus.moon_phase_before(date).unwrap();
+    ///
+    /// assert_eq!(3, phase); // 下弦
+    /// assert_eq!((1999, 12, 29), phase_date.gregorian());
+    /// ```
+    pub fn moon_phase_before(&self, date: Date) -> Option<(i32, Date)> {
+        let phases: Vec<_> = self.moon_phases().collect();
+        if date > phases.last()?.1 {
+            return None;
+        }
+        let i = phases.partition_point(|&(_, phase_date)| phase_date <= date);
+        Some(phases[i.checked_sub(1)?])
+    }
+    /// 本歲內全部節日及其日期，依日期排列。
+    fn festival_dates(&self) -> Vec<(Date, Festival)> {
+        let mut res = Vec::new();
+        for pair in self.months.windows(2) {
+            let (this, next) = (pair[0], pair[1]);
+            if let Month::Common(1) = this.month {
+                res.push((this.date + -1, Festival::NewYearsEve));
+            }
+            for day in 1..=next.date - this.date {
+                if let Some(f) = Festival::from_month_day(this.month, day) {
+                    res.push((this.date + (day - 1), f));
+                }
+            }
+        }
+        res
+    }
+}
+
+/// 某日所在節氣的詳細信息，見 [`Annus::solar_term_detail`]。
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SolarTermInfo {
+    /// 節氣
+    pub term: SolarTerm,
+    /// 該節氣所在歲
+    pub annus: i32,
+    /// 所給日期在交節後第幾日，為 0 則表示當日交節
+    pub days: i32,
+    /// 自交節時刻至所給日期北京時間 0 時的日數（含小數），交節當日為負
+    pub elapsed: f64,
+    /// 交節時刻
+    pub ingress: Tdb,
+    /// 交節時刻的北京時間
+    pub ingress_cst: DateTime,
+}
+
+/// 二十四節氣，依序號 `1..=24` 自立春排至大寒。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SolarTerm {
+    LiChun = 1,
+    YuShui,
+    JingZhe,
+    ChunFen,
+    QingMing,
+    GuYu,
+    LiXia,
+    XiaoMan,
+    MangZhong,
+    XiaZhi,
+    XiaoShu,
+    DaShu,
+    LiQiu,
+    ChuShu,
+    BaiLu,
+    QiuFen,
+    HanLu,
+    ShuangJiang,
+    LiDong,
+    XiaoXue,
+    DaXue,
+    DongZhi,
+    XiaoHan,
+    DaHan,
+}
+
+impl SolarTerm {
+    /// 依序號取得節氣，序號不在 `1..=24` 間則返回 `None`。
+    pub fn from_num(num: i32) -> Option<Self> {
+        use SolarTerm::*;
+        const ALL: [SolarTerm; 24] = [
+            LiChun,
+            YuShui,
+            JingZhe,
+            ChunFen,
+            QingMing,
+            GuYu,
+            LiXia,
+            XiaoMan,
+            MangZhong,
+            XiaZhi,
+            XiaoShu,
+            DaShu,
+            LiQiu,
+            ChuShu,
+            BaiLu,
+            QiuFen,
+            HanLu,
+            ShuangJiang,
+            LiDong,
+            XiaoXue,
+            DaXue,
+            DongZhi,
+            XiaoHan,
+            DaHan,
+        ];
+        ALL.get(usize::try_from(num).ok()?.checked_sub(1)?).copied()
+    }
+    /// 取得節氣序號，`1..=24`。
+    pub fn num(&self) -> i32 {
+        *self as i32
+    }
+    /// 取得節氣名稱。
+    pub fn name(&self) -> &'static str {
+        fmt::solar_term(self.num())
+    }
+}
+
+/// 節氣序號（`1..=24`，立春到大寒）轉為曆表中自冬至起算的索引。
+fn term_index(term: i32) -> usize {
+    (term - 22).rem_euclid(24) as usize
+}
+
+/// 表示給定日期不在該歲，並指出其在前還是在後。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OtherAnnus {
+    Before,
+    After,
+}
+
+/// 表示給定的年月日不存在或不在該歲，見 [`Annus::date_for`]。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LunarDateErr {
+    /// 該月不在本歲
+    OtherAnnus(OtherAnnus),
+    /// 本歲並無該月，如無此閏月，或月序號不在 `1..=12` 間
+    NoSuchMonth,
+    /// 該月並無此日，如小月三十
+    NoSuchDay,
+}
+
+impl std::error::Error for LunarDateErr {}
+
+impl std::fmt::Display for LunarDateErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LunarDateErr::OtherAnnus(OtherAnnus::Before) => write!(f, "month in an earlier annus"),
+            LunarDateErr::OtherAnnus(OtherAnnus::After) => write!(f, "month in a later annus"),
+            LunarDateErr::NoSuchMonth => write!(f, "no such month in annus"),
+            LunarDateErr::NoSuchDay => write!(f, "no such day in month"),
+        }
+    }
+}
+
+/// 表示給定日期不在該歲，或曆表無法取得節氣數據。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SolarTermErr {
+    NoData,
+    OtherAnnus(OtherAnnus),
+}
+
+/// 北京時間（UTC+8）與 UTC 的時差，單位為分鐘
+const CST_OFFSET_MINUTES: i32 = 480;
+
+/// 將給定曆表時間轉為北京時間（UTC+8）日期。
+pub fn date_cst(tdb: Tdb) -> Date {
+    Ut::convert(tdb).date_in_timezone(CST_OFFSET_MINUTES)
+}
+
+/// 同 [`date_cst`]，另返回該時刻在北京時間當日的位置，`0.0` 為 0 時，接近 `1.0` 則接近午夜，可據此判斷交節、合朔時刻距日界的遠近。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::{date_cst, date_cst_with_fraction, ephemeris};
+///
+/// let tdb = ephemeris::Annus::get(2000).unwrap().solar_term[0];
+/// let (date, fraction) = date_cst_with_fraction(tdb);
+///
+/// assert_eq!(date_cst(tdb), date);
+/// assert!((0.0..1.0).contains(&fraction));
+/// ```
+pub fn date_cst_with_fraction(tdb: Tdb) -> (Date, f64) {
+    let (date, secs) = Ut::convert(tdb).local_day(CST_OFFSET_MINUTES);
+    (date, secs / 86400.0)
+}
+
+/// 取得自 `start_year` 起連續 19 歲是否有閏月，即第 `i` 項為 `start_year + i` 歲的 [`Annus::is_leap_year`]。十九年七閏，通常恰有七項為 `true`。
+///
+/// 若曆表缺其中任一歲資料則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::leap_pattern;
+///
+/// let pattern = leap_pattern(2001).unwrap();
+///
+/// assert_eq!(7, pattern.iter().filter(|&&leap| leap).count());
+/// ```
+pub fn leap_pattern(start_year: i32) -> Option<[bool; 19]> {
+    let mut res = [false; 19];
+    for (leap, annus) in res.iter_mut().zip(start_year..) {
+        *leap = Annus::new(annus)?.is_leap_year();
+    }
+    Some(res)
+}
+
+/// 取得所給公元年的干支。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::sexagenary_for_year;
+///
+/// assert_eq!(1, sexagenary_for_year(-2696));
+/// ```
+pub fn sexagenary_for_year(year: i32) -> i32 {
+    (year + 2696).rem_euclid(60) + 1
+}
+
+/// 取得所給公元年的生肖序號，`1..=12` 分別為鼠到豬，見 [`fmt::zodiac`]。
+///
+/// 直接由該年干支的地支得出，不考慮換年時刻；年初尚未過春節的日期屬上一年的生肖，此時應用 [`zodiac_for_date`]。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::{fmt, zodiac_for_year};
+///
+/// assert_eq!("龍", fmt::zodiac(zodiac_for_year(2000)));
+/// assert_eq!("雞", fmt::zodiac(zodiac_for_year(2017)));
+/// ```
+pub fn zodiac_for_year(year: i32) -> i32 {
+    (sexagenary_for_year(year) - 1) % 12 + 1
+}
+
+/// 取得給定日期所屬的生肖序號，以 `boundary` 換年，見 [`sexagenary_year_for_date`]。生肖一般以 [`YearBoundary::SpringFestival`] 換年。曆表無資料時返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::{fmt, zodiac_for_date, YearBoundary::*};
+///
+/// let date = Date::from_gregorian(2017, 1, 27).unwrap(); // 除夕
+///
+/// assert_eq!(Some("猴"), zodiac_for_date(date, SpringFestival).map(fmt::zodiac));
+/// ```
+pub fn zodiac_for_date(date: Date, boundary: YearBoundary) -> Option<i32> {
+    let num = sexagenary_year_for_date(date, boundary)?;
+    Some((num - 1) % 12 + 1)
+}
+
+/// 干支紀年的換年時刻，見 [`sexagenary_year_for_date`]。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum YearBoundary {
+    /// 以立春換年，如命理八字
+    Lichun,
+    /// 以正月初一換年，如生肖
+    SpringFestival,
+}
+
+/// 取得給定日期所在年的干支，年份依 `boundary` 換算，故年初尚未換年的日期屬上一年。曆表無資料時返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::{sexagenary_year_for_date, YearBoundary::*};
+///
+/// let date = Date::from_gregorian(2017, 1, 30).unwrap(); // 正月初三，立春之前
+///
+/// assert_eq!(Some(33), sexagenary_year_for_date(date, Lichun)); // 丙申
+/// assert_eq!(Some(34), sexagenary_year_for_date(date, SpringFestival)); // 丁酉
+/// ```
+pub fn sexagenary_year_for_date(date: Date, boundary: YearBoundary) -> Option<i32> {
+    let year = match boundary {
+        YearBoundary::Lichun => {
+            let year = date.gregorian().0;
+            let lichun = ephemeris::Annus::get(year)?.solar_term[term_index(1)];
+            if date < date_cst(lichun) {
+                year - 1
+            } else {
+                year
+            }
+        }
+        YearBoundary::SpringFestival => Annus::from_date(date)?.ymd_for(date).ok()?.0,
+    };
+    Some(sexagenary_for_year(year))
+}
+
+/// 取得公元 `year` 年的正月初一（春節）日期。
+///
+/// 若曆表無該年資料則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::chinese_new_year;
+///
+/// assert_eq!(Date::from_gregorian(2017, 1, 28), chinese_new_year(2017));
+/// ```
+pub fn chinese_new_year(year: i32) -> Option<Date> {
+    let annus = Annus::new(year)?;
+    let month = annus
+        .months
+        .iter()
+        .find(|m| m.month == Month::Common(1))
+        .expect("正月 not in annus");
+    Some(month.date)
+}
+
+/// 取得公元 `lunar_year` 年（以正月為首）首尾兩日，格式為 `(正月初一, 次年正月初一前一日)`。
+///
+/// 若曆表無該年或次年資料則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::lunar_year_gregorian_span;
+///
+/// let (first, last) = lunar_year_gregorian_span(2017).unwrap();
+/// assert_eq!((2017, 1, 28), first.gregorian());
+/// assert_eq!((2018, 2, 15), last.gregorian());
+/// ```
+pub fn lunar_year_gregorian_span(lunar_year: i32) -> Option<(Date, Date)> {
+    let first = chinese_new_year(lunar_year)?;
+    let next = chinese_new_year(lunar_year.checked_add(1)?)?;
+    Some((first, next + -1))
+}
+
+/// 取得公元 `lunar_year` 年（以正月為首）所跨的兩個公曆年，如 2023 年（癸卯年）為 `(2023, 2024)`，可用於「2023/2024 癸卯年」一類標記。
+///
+/// 若曆表無該年或次年資料則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::{fmt, lunar_year_gregorian_years, sexagenary_for_year};
+///
+/// let (start, end) = lunar_year_gregorian_years(2023).unwrap();
+/// let label = format!("{}/{} {}年", start, end, fmt::sexagenary(sexagenary_for_year(2023)));
+/// assert_eq!("2023/2024 癸卯年", label);
+/// ```
+pub fn lunar_year_gregorian_years(lunar_year: i32) -> Option<(i32, i32)> {
+    let (first, last) = lunar_year_gregorian_span(lunar_year)?;
+    Some((first.gregorian().0, last.gregorian().0))
+}
+
+/// 依次取得六十干支名稱，自甲子至癸亥，第 `i` 項為干支序號 `i + 1` 之名。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::sexagenary_cycle_names;
+///
+/// let names = sexagenary_cycle_names();
+///
+/// assert_eq!("甲子", names[0]);
+/// assert_eq!("癸亥", names[59]);
+/// ```
+pub fn sexagenary_cycle_names() -> [String; 60] {
+    let names: Vec<_> = (1..=60).map(fmt::sexagenary).collect();
+    names.try_into().unwrap()
+}
+
+/// 將干支序號 `num`（`1..=60`）前進 `offset`（可為負），循環回到 `1..=60`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::add_sexagenary;
+///
+/// assert_eq!(1, add_sexagenary(60, 1)); // 癸亥後為甲子
+/// assert_eq!(60, add_sexagenary(1, -1));
+/// ```
+pub fn add_sexagenary(num: i32, offset: i32) -> i32 {
+    (num - 1 + offset.rem_euclid(60)).rem_euclid(60) + 1
+}
+
+/// 自干支序號 `from` 向前數至 `to` 的距離，`0..60`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::{add_sexagenary, sexagenary_between};
+///
+/// assert_eq!(2, sexagenary_between(59, 1));
+/// assert_eq!(1, add_sexagenary(59, sexagenary_between(59, 1)));
+/// ```
+pub fn sexagenary_between(from: i32, to: i32) -> i32 {
+    (to - from).rem_euclid(60)
+}
+
+const LUNAR_MANSION_NAMES: [&str; 28] = [
+    "角", "亢", "氐", "房", "心", "尾", "箕", "斗", "牛", "女", "虛", "危", "室", "壁", "奎", "婁",
+    "胃", "昴", "畢", "觜", "參", "井", "鬼", "柳", "星", "張", "翼", "軫",
+];
+
+/// 取得 `date` 當日值日的二十八宿，格式為 `(序號, 宿名)`，序號 `1..=28` 自角宿起。
+///
+/// 二十八宿按日依次輪值，以公元 2000 年 1 月 13 日（JDN 2451557）為角宿，與七曜相應（角宿值木曜，即星期四）。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::lunar_mansion;
+/// use kalendarilo::date::Date;
+///
+/// let date = Date::from_gregorian(2000, 1, 13).unwrap();
+/// assert_eq!((1, "角"), lunar_mansion(date));
+/// assert_eq!((28, "軫"), lunar_mansion(date + -1));
+/// ```
+pub fn lunar_mansion(date: Date) -> (i32, &'static str) {
+    const EPOCH: i64 = 2451557;
+    let index = (date.jdn() as i64 - EPOCH).rem_euclid(28) as usize;
+    (index as i32 + 1, LUNAR_MANSION_NAMES[index])
+}
+
+/// 取得公元 `year` 年三伏（初伏、中伏、末伏）的起止日期，格式為 `[(首日, 末日次日); 3]`。
+///
+/// 自夏至當日起數第三個庚日為初伏首日，第四個庚日為中伏首日，立秋當日或其後首個庚日為末伏首日；初伏、末伏各十日，中伏十日或二十日。
+///
+/// 若曆表無該年資料則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::san_fu;
+///
+/// let [chu, zhong, mo] = san_fu(2020).unwrap();
+///
+/// assert_eq!("2020-07-16", chu.0.iso_gregorian());
+/// assert_eq!("2020-07-26", zhong.0.iso_gregorian());
+/// assert_eq!("2020-08-15", mo.0.iso_gregorian());
+/// assert_eq!("2020-08-25", mo.1.iso_gregorian()); // 8 月 24 日出伏
+/// ```
+pub fn san_fu(year: i32) -> Option<[(Date, Date); 3]> {
+    // 庚日干支序號模 10 為 7
+    fn next_geng(date: Date) -> Date {
+        date + (7 - date.tian_gan_day()).rem_euclid(10)
+    }
+    let ephemeris = ephemeris::Annus::get(year)?;
+    let summer_solstice = date_cst(ephemeris.solar_term[12]);
+    let start_of_autumn = date_cst(ephemeris.solar_term[15]);
+
+    let chu = next_geng(summer_solstice) + 20;
+    let zhong = chu + 10;
+    let mo = next_geng(start_of_autumn);
+    Some([(chu, zhong), (zhong, mo), (mo, mo + 10)])
+}
+
+/// 取得自公元 `year` 年冬至起「數九」各九的首日。
+///
+/// 冬至當日為一九首日，每九九日，共九九八十一日。
+///
+/// 若曆表無該年冬至資料則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::shu_jiu;
+///
+/// let jiu = shu_jiu(2020).unwrap();
+///
+/// assert_eq!("2020-12-21", jiu[0].iso_gregorian());
+/// assert_eq!("2021-03-03", jiu[8].iso_gregorian()); // 九九首日
+/// ```
+pub fn shu_jiu(year: i32) -> Option<[Date; 9]> {
+    let winter_solstice = date_cst(ephemeris::Annus::get(year)?.solar_term[24]);
+    let mut res = [winter_solstice; 9];
+    for (i, date) in (0..).zip(&mut res) {
+        *date = winter_solstice + 9 * i;
+    }
+    Some(res)
+}
+
+/// 取得給定日期的建除十二神，格式為 `(序號, 名稱)`，序號 `1..=12` 對應建、除、滿、平、定、執、破、危、成、收、開、閉。
+///
+/// 月建依節氣而定（立春起為寅月，驚蟄起為卯月，餘類推），日支與月建相同之日為「建」，其後依日支順次排列，故交節之日與前一日同神。
+///
+/// 若曆表無法取得該日節氣數據則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::jian_chu;
+///
+/// let date = Date::from_gregorian(2017, 2, 8).unwrap(); // 寅月寅日
+///
+/// assert_eq!(Some((1, "建")), jian_chu(date));
+/// ```
+pub fn jian_chu(date: Date) -> Option<(i32, &'static str)> {
+    const NAMES: &[&str] = &[
+        "建", "除", "滿", "平", "定", "執", "破", "危", "成", "收", "開", "閉",
+    ];
+    let (_, term, _) = Annus::from_date(date)?.solar_term_for(date).ok()?;
+    // 地支以子為 0，立春、雨水所在寅月為 2
+    let month_branch = (term - 1) / 2 + 2;
+    let day_branch = date.sexagenary() - 1;
+    let idx = (day_branch - month_branch).rem_euclid(12);
+    Some((idx + 1, NAMES[idx as usize]))
+}
+
+/// 取得給定日期以節氣劃分的干支年月，格式為 `(年, 月)`。
+///
+/// 與農曆月不同，干支月以「節」為界：立春起為寅月（`1`），驚蟄起為卯月（`2`），餘類推至小寒起為丑月（`12`）；干支年亦以立春為界，年份以該立春所在公元年表示，可配合 [`sexagenary_for_year`] 取得年干支。
+///
+/// 若曆表無法取得該日節氣數據則返回 `None`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::ganzhi_month_for;
+///
+/// let date = Date::from_gregorian(2017, 2, 3).unwrap(); // 立春
+///
+/// assert_eq!(Some((2017, 1)), ganzhi_month_for(date));
+/// assert_eq!(Some((2016, 12)), ganzhi_month_for(date + -1));
+/// ```
+pub fn ganzhi_month_for(date: Date) -> Option<(i32, i32)> {
+    let (annus, term, _) = Annus::from_date(date)?.solar_term_for(date).ok()?;
+    let year = if term >= 22 { annus - 1 } else { annus };
+    Some((year, (term - 1) / 2 + 1))
+}
+
+/// 取得 `start_annus..=end_annus` 各歲全部交節日期及其節氣序號（`1..=24`，立春到大寒）。
+///
+/// 每歲收錄自冬至至大雪的 24 個節氣，曆表無資料的歲略去。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::solar_term_calendar;
+///
+/// let terms = solar_term_calendar(2000, 2009);
+///
+/// assert_eq!(240, terms.len());
+/// assert_eq!(Some(&22), terms.get(&Date::from_gregorian(1999, 12, 22).unwrap())); // 冬至
+/// ```
+pub fn solar_term_calendar(start_annus: i32, end_annus: i32) -> BTreeMap<Date, i32> {
+    let mut res = BTreeMap::new();
+    for annus in start_annus..=end_annus {
+        let ephemeris = match ephemeris::Annus::get(annus) {
+            Some(e) => e,
+            None => continue,
+        };
+        for (idx, &tdb) in (0..24).zip(&ephemeris.solar_term[..24]) {
+            res.insert(date_cst(tdb), (idx + 21) % 24 + 1);
+        }
+    }
+    res
+}
+
+/// 取得給定日期之後（不含當日）的 `n` 個交節日期及其節氣序號（`1..=24`，立春到大寒），依日期排列，可跨越多歲。
+///
+/// 曆表資料不足時，返回的項數可能少於 `n`。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::upcoming_solar_terms;
+///
+/// let terms = upcoming_solar_terms(Date::from_gregorian(1999, 12, 22).unwrap(), 1);
+///
+/// assert_eq!(vec![(Date::from_gregorian(2000, 1, 6).unwrap(), 23)], terms); // 小寒
+/// ```
+pub fn upcoming_solar_terms(from: Date, n: usize) -> Vec<(Date, i32)> {
+    let mut res = Vec::with_capacity(n);
+    let mut annus = from.gregorian().0;
+    while res.len() < n {
+        let ephemeris = match ephemeris::Annus::get(annus) {
+            Some(e) => e,
+            None => break,
+        };
+        for (idx, &tdb) in (0..24).zip(&ephemeris.solar_term[..24]) {
+            let date = date_cst(tdb);
+            if date > from && res.len() < n {
+                res.push((date, (idx + 21) % 24 + 1));
+            }
+        }
+        annus += 1;
+    }
+    res
+}
+
+/// 傳統節日，均以農曆平月日期而定（閏月不計）。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Festival {
+    /// 春節，正月初一
+    SpringFestival,
+    /// 元宵，正月十五
+    Lantern,
+    /// 端午，五月初五
+    DragonBoat,
+    /// 七夕，七月初七
+    Qixi,
+    /// 中元，七月十五
+    Zhongyuan,
+    /// 中秋，八月十五
+    MidAutumn,
+    /// 重陽，九月初九
+    DoubleNinth,
+    /// 臘八，臘月初八
+    Laba,
+    /// 除夕，正月初一前一日（臘月廿九或三十）
+    NewYearsEve,
+}
+
+impl Festival {
+    /// 取得節日名稱。
+    pub fn name(&self) -> &'static str {
+        use Festival::*;
+        match self {
+            SpringFestival => "春節",
+            Lantern => "元宵",
+            DragonBoat => "端午",
+            Qixi => "七夕",
+            Zhongyuan => "中元",
+            MidAutumn => "中秋",
+            DoubleNinth => "重陽",
+            Laba => "臘八",
+            NewYearsEve => "除夕",
+        }
+    }
+
+    /// 依固定農曆月日取得節日，不含除夕。
+    fn from_month_day(month: Month, day: i32) -> Option<Self> {
+        use Festival::*;
+        Some(match (month, day) {
+            (Month::Common(1), 1) => SpringFestival,
+            (Month::Common(1), 15) => Lantern,
+            (Month::Common(5), 5) => DragonBoat,
+            (Month::Common(7), 7) => Qixi,
+            (Month::Common(7), 15) => Zhongyuan,
+            (Month::Common(8), 15) => MidAutumn,
+            (Month::Common(9), 9) => DoubleNinth,
+            (Month::Common(12), 8) => Laba,
+            _ => return None,
+        })
+    }
+}
+
+/// 取得給定日期所逢的傳統節日，平常日子或曆表無資料時返回 `None`。
+///
+/// 節日均在平月，閏月同日不算；除夕則為正月初一前一日，不論臘月大小。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::{festival_for, Festival};
+///
+/// let date = Date::from_gregorian(2017, 10, 4).unwrap();
+///
+/// assert_eq!(Some(Festival::MidAutumn), festival_for(date));
+/// assert_eq!(None, festival_for(date + 1));
+/// ```
+pub fn festival_for(date: Date) -> Option<Festival> {
+    let (_, month, day) = Annus::from_date(date)?.ymd_for(date).ok()?;
+    if let Some(f) = Festival::from_month_day(month, day) {
+        return Some(f);
+    }
+    let next = date + 1;
+    match Annus::from_date(next)?.ymd_for(next).ok()? {
+        (_, Month::Common(1), 1) => Some(Festival::NewYearsEve),
+        _ => None,
+    }
+}
+
+/// 某日所逢的節日、節氣等，見 [`observances`]。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Observance {
+    /// 傳統節日
+    Festival(Festival),
+    /// 交節，值為節氣序號（`1..=24`，立春到大寒）
+    SolarTerm(i32),
+    /// 四絕，即立春、立夏、立秋、立冬前一日，值為其後一日的節氣序號
+    SiJue(i32),
+    /// 四離，即春分、夏至、秋分、冬至前一日，值為其後一日的節氣序號
+    SiLi(i32),
+}
+
+impl Observance {
+    /// 取得名稱，交節日為節氣名。
+    pub fn name(&self) -> &'static str {
+        match self {
+            Observance::Festival(f) => f.name(),
+            Observance::SolarTerm(term) => fmt::solar_term(*term),
+            Observance::SiJue(_) => "四絕",
+            Observance::SiLi(_) => "四離",
+        }
+    }
+}
+
+/// 取得給定日期所逢全部節日、節氣、四絕四離，依上列次序排列。平常日子或曆表無資料時返回空列表。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::Date;
+/// use kalendarilo::chinese::{observances, Festival, Observance};
+///
+/// let date = Date::from_gregorian(2017, 1, 27).unwrap();
+///
+/// assert_eq!(vec![Observance::Festival(Festival::NewYearsEve)], observances(date));
+/// ```
+pub fn observances(date: Date) -> Vec<Observance> {
+    let mut res = Vec::new();
+    if let Some(annus) = Annus::from_date(date) {
+        if let Ok((_, month, day)) = annus.ymd_for(date) {
+            if let Some(f) = Festival::from_month_day(month, day) {
+                res.push(Observance::Festival(f));
+            }
+        }
+    }
+    if let Some((_, Month::Common(1), 1)) =
+        Annus::from_date(date + 1).and_then(|a| a.ymd_for(date + 1).ok())
+    {
+        res.push(Observance::Festival(Festival::NewYearsEve));
+    }
+    if let Some(term) = solar_term_on(date) {
+        res.push(Observance::SolarTerm(term));
+    }
+    match solar_term_on(date + 1) {
+        Some(term @ (1 | 7 | 13 | 19)) => res.push(Observance::SiJue(term)),
+        Some(term @ (4 | 10 | 16 | 22)) => res.push(Observance::SiLi(term)),
+        _ => {}
+    }
+    res
+}
+
+/// 若給定日期交節，取得其節氣序號。
+fn solar_term_on(date: Date) -> Option<i32> {
+    match Annus::from_date(date)?.solar_term_for(date) {
+        Ok((_, term, 0)) => Some(term),
+        _ => None,
+    }
+}
+
+/// 將一歲的月首、24 節氣及節日匯出為 iCalendar（RFC 5545）格式，每項為一個全日 `VEVENT`，依日期排列。
+///
+/// 月首事件摘要為月名，節氣為節氣名，節日為節日名。`UID` 由日期、事件種類及摘要組成，不隨事件增減而變。
+/// `dtstamp` 為生成時刻，填入各事件之 `DTSTAMP`（UTC），由調用方給出以便輸出可重現。
+/// 各行以 CRLF 結束，超過 75 字節的行依規定折行。
+///
+/// # 用例
+///
+/// ```
+/// use kalendarilo::chinese::{annus_to_ics, Annus};
+/// use kalendarilo::time_scales::Ut;
+///
+/// let ics = annus_to_ics(&Annus::new(2000).unwrap(), Ut(2451545.0));
+///
+/// assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert!(ics.contains("DTSTAMP:20000101T120000Z\r\n"));
+/// assert!(ics.contains("DTSTART;VALUE=DATE:19991222\r\nDTEND;VALUE=DATE:19991223\r\nSUMMARY:冬至\r\n"));
+/// ```
+pub fn annus_to_ics(annus: &Annus, dtstamp: Ut) -> String {
+    let mut events: Vec<(Date, &str, String)> = Vec::new();
+    for m in &annus.months[..annus.months.len() - 1] {
+        events.push((m.date, "month", m.month.name()));
+    }
+    for (&date, idx) in annus.term_dates[..24].iter().zip(0..) {
+        let term = (idx + 21) % 24 + 1;
+        events.push((date, "term", fmt::solar_term(term).to_string()));
+    }
+    for (date, f) in annus.festival_dates() {
+        events.push((date, "festival", f.name().to_string()));
+    }
+    events.sort_by_key(|&(date, _, _)| date);
+
+    let (stamp_date, h, m, sec) = dtstamp.civil_time(0);
+    let stamp = format!(
+        "{}T{:02}{:02}{:02}Z",
+        stamp_date.iso_gregorian_basic(),
+        h,
+        m,
+        sec
+    );
+
+    let mut res = String::new();
+    let mut line = |s: &str| ics_line(&mut res, s);
+    line("BEGIN:VCALENDAR");
+    line("VERSION:2.0");
+    line("PRODID:-//kalendarilo//Chinese calendar//ZH");
+    line("CALSCALE:GREGORIAN");
+    for (date, kind, summary) in &events {
+        let start = date.iso_gregorian_basic();
+        line("BEGIN:VEVENT");
+        line(&format!("UID:{}-{}-{}@kalendarilo", start, kind, summary));
+        line(&format!("DTSTAMP:{}", stamp));
+        line(&format!("DTSTART;VALUE=DATE:{}", start));
+        line(&format!(
+            "DTEND;VALUE=DATE:{}",
+            (*date + 1).iso_gregorian_basic()
+        ));
+        line(&format!("SUMMARY:{}", summary));
+        line("TRANSP:TRANSPARENT");
+        line("END:VEVENT");
+    }
+    line("END:VCALENDAR");
+    res
+}
+
+/// 寫入一行 iCalendar 內容，每行至多 75 字節，超出部分以 CRLF 加空格折行，不拆分 UTF-8 字符。
+fn ics_line(out: &mut String, mut s: &str) {
+    let mut limit = 75;
+    while s.len() > limit {
+        let mut split = limit;
+        while !s.is_char_boundary(split) {
+            split -= 1;
+        }
+        out.push_str(&s[..split]);
+        out.push_str("\r\n ");
+        s = &s[split..];
+        limit = 74;
+    }
+    out.push_str(s);
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_date() {
+        let dataset = [
+            (2017, (2017, 1, 27)),
+            (2017, (2017, 12, 17)),
+            (2018, (2017, 12, 18)),
+        ];
+        for (std, (y, m, d)) in dataset {
+            assert_eq!(
+                Some(std),
+                Annus::from_date(Date::from_gregorian(y, m, d).unwrap()).map(|a| a.annus)
+            );
+        }
+    }
+
+    #[test]
+    fn annus_boundaries() {
+        for y in [2000, 2014, 2015, 2017] {
+            let annus = Annus::new(y).unwrap();
+            let sentinel = annus.months.last().unwrap().date;
+            let last = sentinel + -1;
+            assert!(annus.ymd_for(last).is_ok(), "{}", y);
+            assert_eq!(Some(y), Annus::from_date(last).map(|a| a.annus));
+            assert_eq!(Err(OtherAnnus::After), annus.ymd_for(sentinel));
+            let next = Annus::from_date(sentinel).unwrap();
+            assert_eq!((y + 1, sentinel), (next.annus, next.months[0].date));
+            assert_eq!(Ok((y, Month::Common(11), 1)), next.ymd_for(sentinel));
+        }
+
+        // 朔旦冬至：2014-12-22 既是冬至，亦為冬月初一
+        let annus = Annus::new(2014).unwrap();
+        assert!(annus.is_leap_year());
+        assert_eq!(14, annus.months.len());
+        assert_eq!("2014-12-22", annus.months[13].date.iso_gregorian());
+        let date = Date::from_gregorian(2014, 11, 22).unwrap();
+        assert_eq!(Some(2014), Annus::from_date(date).map(|a| a.annus));
+        assert_eq!(Ok((2014, Month::Common(10), 1)), annus.ymd_for(date));
+        assert_eq!(Ok((2014, Month::Leap(9), 29)), annus.ymd_for(date + -1));
+    }
+
+    #[test]
+    fn from_ephemeris() {
+        let annus = Annus::new(2000).unwrap();
+        let from_eph = Annus::from_ephemeris(ephemeris::Annus::get(2000).unwrap());
+        assert_eq!(annus, from_eph);
+        let dates = |a: &Annus| {
+            a.months
+                .iter()
+                .map(|m| (m.month, m.date))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(dates(&annus), dates(&from_eph));
+        assert_eq!(annus.term_dates, from_eph.term_dates);
+        assert!(std::ptr::eq(annus.ephemeris, from_eph.ephemeris));
+    }
+
+    #[test]
+    fn annus_eq() {
+        use std::collections::HashSet;
+        assert_eq!(Annus::new(2000).unwrap(), Annus::new(2000).unwrap());
+        assert_ne!(Annus::new(2000).unwrap(), Annus::new(2001).unwrap());
+        let set: HashSet<_> = [2000, 2001, 2000]
+            .iter()
+            .map(|&y| Annus::new(y).unwrap())
+            .collect();
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn from_winter_solstice() {
+        let dataset = [
+            ((1999, 12, 22), Some(2000)),
+            ((2016, 12, 21), Some(2017)),
+            ((2016, 12, 22), None),
+            ((2017, 6, 21), None),
+            ((999, 12, 22), None),
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(std, Annus::from_winter_solstice(date).map(|a| a.annus));
+        }
+    }
+
+    #[test]
+    fn months() {
+        let annus = Annus::new(2000).unwrap();
+        let stds = [
+            (11, "1999-12-08"),
+            (12, "2000-01-07"),
+            (1, "2000-02-05"),
+            (2, "2000-03-06"),
+            (3, "2000-04-05"),
+            (4, "2000-05-04"),
+            (5, "2000-06-02"),
+            (6, "2000-07-02"),
+            (7, "2000-07-31"),
+            (8, "2000-08-29"),
+            (9, "2000-09-28"),
+            (10, "2000-10-27"),
             (11, "2000-11-26"),
         ];
         assert_eq!(stds.len(), annus.months.len());
@@ -319,36 +1900,191 @@ mod tests {
     }
 
     #[test]
-    fn leap_months() {
-        let stds = [
-            (11, "2016-11-29"),
-            (12, "2016-12-29"),
-            (1, "2017-01-28"),
-            (2, "2017-02-26"),
-            (3, "2017-03-28"),
-            (4, "2017-04-26"),
-            (5, "2017-05-26"),
-            (6, "2017-06-24"),
-            (-6, "2017-07-23"),
-            (7, "2017-08-22"),
-            (8, "2017-09-20"),
-            (9, "2017-10-20"),
-            (10, "2017-11-18"),
-            (11, "2017-12-18"),
-        ];
+    fn leap_months() {
+        let stds = [
+            (11, "2016-11-29"),
+            (12, "2016-12-29"),
+            (1, "2017-01-28"),
+            (2, "2017-02-26"),
+            (3, "2017-03-28"),
+            (4, "2017-04-26"),
+            (5, "2017-05-26"),
+            (6, "2017-06-24"),
+            (-6, "2017-07-23"),
+            (7, "2017-08-22"),
+            (8, "2017-09-20"),
+            (9, "2017-10-20"),
+            (10, "2017-11-18"),
+            (11, "2017-12-18"),
+        ];
+        let annus = Annus::new(2017).unwrap();
+        for (std, month) in stds.iter().zip(&annus.months) {
+            let std_month = if std.0 > 0 {
+                Month::Common(std.0)
+            } else {
+                Month::Leap(-std.0)
+            };
+            assert_eq!(
+                (std_month, std.1.into()),
+                (month.month, month.date.iso_gregorian())
+            );
+        }
+        assert_eq!(stds.len(), annus.months.len());
+    }
+
+    #[test]
+    fn zhongqi_flags() {
+        let annus = Annus::new(2017).unwrap();
+        assert_eq!(Month::Leap(6), annus.months[8].month);
+        assert_eq!(Some(false), annus.month_has_zhongqi(8));
+        assert_eq!(Some(true), annus.month_has_zhongqi(7));
+        assert_eq!(Some(true), annus.month_has_zhongqi(9));
+        let last = annus.months.len() - 1;
+        for (idx, m) in annus.months[..last].iter().enumerate() {
+            assert_eq!(
+                Some(!m.month.is_leap()),
+                annus.month_has_zhongqi(idx),
+                "{:?}",
+                m
+            );
+        }
+        assert_eq!(None, annus.month_has_zhongqi(last));
+        assert_eq!(None, annus.month_has_zhongqi(usize::MAX));
+        let annus = Annus::new(2000).unwrap();
+        assert!((0..12).all(|idx| annus.month_has_zhongqi(idx) == Some(true)));
+    }
+
+    #[test]
+    fn weekday_dates() {
+        for y in [2000, 2017] {
+            let annus = Annus::new(y).unwrap();
+            let (start, end) = (annus.months[0].date, annus.months.last().unwrap().date);
+            let mut total = 0;
+            for weekday in 1..=7 {
+                let dates = annus.weekday_dates(weekday);
+                let std = (0..end - start)
+                    .filter(|&i| (start + i).day_of_week() == weekday)
+                    .count();
+                assert_eq!(std, dates.len(), "{} {}", y, weekday);
+                assert!(dates.iter().all(|d| d.day_of_week() == weekday));
+                assert!(dates.windows(2).all(|w| w[1] - w[0] == 7));
+                total += dates.len();
+            }
+            assert_eq!((end - start) as usize, total);
+        }
+        assert_eq!(50, Annus::new(2000).unwrap().weekday_dates(7).len());
+        assert_eq!(55, Annus::new(2017).unwrap().weekday_dates(7).len());
+    }
+
+    #[test]
+    fn sexagenary_days() {
+        let annus = Annus::new(2000).unwrap();
+        let start = annus.months[0].date;
+        assert_eq!(start.sexagenary(), annus.first_day_sexagenary());
+        assert_eq!(
+            start,
+            annus.first_sexagenary_day(annus.first_day_sexagenary())
+        );
+        let jiazi = annus.first_sexagenary_day(1);
+        assert_eq!((2000, 1, 7), jiazi.gregorian());
+        assert!(jiazi - start < 60);
+        for num in 1..=60 {
+            let date = annus.first_sexagenary_day(num);
+            assert_eq!(num, date.sexagenary());
+            assert!((0..60).contains(&(date - start)));
+        }
+    }
+
+    #[test]
+    fn year_epochs() {
+        let annus = Annus::new(2017).unwrap();
+        let dataset = [
+            ((2016, 12, 20), Month::Common(11)),
+            ((2017, 1, 20), Month::Common(12)),
+            ((2017, 2, 20), Month::Common(1)),
+            ((2017, 7, 30), Month::Leap(6)),
+            ((2017, 12, 1), Month::Common(10)),
+        ];
+        for ((y, m, d), month) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            let ymd = annus.ymd_for(date).unwrap();
+            assert_eq!(Ok(ymd), annus.ymd_for_with_epoch(date, 1));
+            assert_eq!(month, ymd.1);
+            // 建子：全歲同屬一年
+            assert_eq!(Ok((2017, ymd.1, ymd.2)), annus.ymd_for_with_epoch(date, 11));
+            // 建丑：唯十一月屬上一年
+            let std = if month == Month::Common(11) {
+                2016
+            } else {
+                2017
+            };
+            assert_eq!(Ok((std, ymd.1, ymd.2)), annus.ymd_for_with_epoch(date, 12));
+        }
+        assert_eq!(
+            Ok((2016, Month::Leap(6), 7)),
+            annus.ymd_for_with_epoch(Date::from_gregorian(2017, 7, 29).unwrap(), 7)
+        );
+    }
+
+    #[test]
+    fn winter_solstice_day() {
+        let annus = Annus::new(2000).unwrap();
+        let ws = Date::from_gregorian(1999, 12, 22).unwrap();
+        assert_eq!(Ok((1999, Month::Common(11), 15)), annus.ymd_for(ws));
+        assert_eq!(15, annus.winter_solstice_lunar_day());
+        // 朔旦冬至
+        assert_eq!(1, Annus::new(2015).unwrap().winter_solstice_lunar_day());
+        for y in ephemeris::ANNUS_RANGE {
+            let day = Annus::new(y).unwrap().winter_solstice_lunar_day();
+            assert!((1..=30).contains(&day), "{}", y);
+        }
+    }
+
+    #[test]
+    fn leap_context() {
+        let annus = Annus::new(2017).unwrap();
+        for num in 1..=12 {
+            let std = num == 6;
+            assert_eq!(std, annus.is_leap_context(Month::Common(num)), "{}", num);
+            assert_eq!(std, annus.is_leap_context(Month::Leap(num)), "{}", num);
+        }
+        let annus = Annus::new(2000).unwrap();
+        assert!((1..=12).all(|num| !annus.is_leap_context(Month::Common(num))));
+    }
+
+    #[test]
+    fn label_sorted_dates() {
         let annus = Annus::new(2017).unwrap();
-        for (std, month) in stds.iter().zip(&annus.months) {
-            let std_month = if std.0 > 0 {
-                Month::Common(std.0)
-            } else {
-                Month::Leap(-std.0)
-            };
+        let start = annus.months[0].date + -3;
+        let end = annus.months.last().unwrap().date + 3;
+        let dates: Vec<_> = (0..end - start).map(|i| start + i).collect();
+        let std: Vec<_> = dates.iter().map(|&date| annus.ymd_for(date)).collect();
+        assert_eq!(std, annus.label_sorted(&dates));
+
+        let shuffled: Vec<_> = dates.iter().rev().step_by(7).copied().collect();
+        let std: Vec<_> = shuffled.iter().map(|&date| annus.ymd_for(date)).collect();
+        assert_eq!(std, annus.label_sorted(&shuffled));
+        assert!(annus.label_sorted(&[]).is_empty());
+    }
+
+    #[test]
+    fn leap_years() {
+        for y in 1990..=2030 {
+            let annus = Annus::new(y).unwrap();
+            // `months` 含次歲首月，故閏歲共 14 項
+            assert_eq!(annus.months.len() == 14, annus.is_leap_year(), "{}", y);
             assert_eq!(
-                (std_month, std.1.into()),
-                (month.month, month.date.iso_gregorian())
+                annus.months.iter().any(|m| m.month.is_leap()),
+                annus.is_leap_year(),
+                "{}",
+                y
             );
         }
-        assert_eq!(stds.len(), annus.months.len());
+        // 隨 `months` 變動
+        let mut annus = Annus::new(2017).unwrap();
+        assert!(annus.is_leap_year());
+        annus.months.remove(8);
+        assert!(!annus.is_leap_year());
     }
 
     #[test]
@@ -371,6 +2107,256 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let annus = Annus::new(2017).unwrap();
+        let json = serde_json::to_string(&annus.months).unwrap();
+        let months: Vec<NewMoon> = serde_json::from_str(&json).unwrap();
+        assert_eq!(annus.months.len(), months.len());
+        for (a, b) in annus.months.iter().zip(&months) {
+            assert_eq!((a.month, a.date), (b.month, b.date));
+        }
+        assert!(json.contains(r#"{"month":{"Leap":6},"date":"2017-07-23"}"#));
+    }
+
+    #[test]
+    fn annus_days() {
+        use Month::*;
+        let annus = Annus::new(2017).unwrap();
+        let days: Vec<_> = annus.days().collect();
+        let (first, last) = (annus.months[0].date, annus.months.last().unwrap().date);
+        assert_eq!((last - first) as usize, days.len());
+        let date = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        assert_eq!((date(2016, 11, 29), 2016, Common(11), 1), days[0]);
+        assert_eq!((date(2017, 7, 23), 2017, Leap(6), 1), days[236]);
+        assert_eq!(
+            (date(2017, 12, 17), 2017, Common(10), 30),
+            *days.last().unwrap()
+        );
+        for annus in 1975..=2049 {
+            let annus = Annus::new(annus).unwrap();
+            let mut expected = annus.months[0].date;
+            for (date, y, m, d) in annus.days() {
+                assert_eq!(expected, date);
+                assert_eq!(Ok((y, m, d)), annus.ymd_for(date));
+                expected = date + 1;
+            }
+            assert_eq!(annus.months.last().unwrap().date, expected);
+        }
+    }
+
+    #[test]
+    fn solar_term_dates() {
+        let annus = Annus::new(2024).unwrap();
+        let date = |y, m, d| Date::from_gregorian(y, m, d);
+        assert_eq!(date(2024, 4, 4), annus.solar_term_date(5));
+        assert_eq!(date(2023, 12, 22), annus.solar_term_date(22));
+        assert_eq!(date(2024, 2, 4), annus.solar_term_date(1));
+        assert_eq!(date(2024, 1, 20), annus.solar_term_date(24));
+        for annus in 1975..=2049 {
+            let annus = Annus::new(annus).unwrap();
+            for term in 1..=24 {
+                match annus.solar_term_date(term) {
+                    Some(date) => {
+                        assert_eq!(Ok((annus.annus, term, 0)), annus.solar_term_for(date));
+                        assert!(annus.solar_term_month(term).is_some());
+                    }
+                    None => {
+                        assert!((20..=21).contains(&term), "{}", term);
+                        assert_eq!(None, annus.solar_term_month(term));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn moon_phases() {
+        let annus = Annus::new(2000).unwrap();
+        let phases: Vec<_> = annus.moon_phases().collect();
+        assert_eq!(60, phases.len());
+        for (i, &(phase, _)) in phases.iter().enumerate() {
+            assert_eq!(i as i32 % 4, phase);
+        }
+        for pair in phases.windows(2) {
+            assert!((6..=9).contains(&(pair[1].1 - pair[0].1)), "{:?}", pair);
+        }
+        let new_moons: Vec<_> = phases.iter().filter(|p| p.0 == 0).map(|p| p.1).collect();
+        for m in &annus.months {
+            assert!(new_moons.contains(&m.date));
+        }
+
+        let date = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        assert_eq!(None, annus.moon_phase_before(date(1999, 12, 7)));
+        assert_eq!(
+            Some((0, date(1999, 12, 8))),
+            annus.moon_phase_before(date(1999, 12, 8))
+        );
+        assert_eq!(
+            Some((3, date(1999, 12, 29))),
+            annus.moon_phase_before(date(2000, 1, 1))
+        );
+        for &(phase, phase_date) in &phases {
+            assert_eq!(
+                Some((phase, phase_date)),
+                annus.moon_phase_before(phase_date)
+            );
+        }
+        let last = phases.last().unwrap().1;
+        assert!(annus.moon_phase_before(last).is_some());
+        assert_eq!(None, annus.moon_phase_before(last + 1));
+    }
+
+    #[test]
+    fn festivals() {
+        use self::Festival::*;
+        let g = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        let dataset = [
+            ((2017, 1, 28), SpringFestival),
+            ((2017, 2, 11), Lantern),
+            ((2017, 5, 30), DragonBoat),
+            ((2017, 8, 28), Qixi),
+            ((2017, 9, 5), Zhongyuan),
+            ((2017, 10, 4), MidAutumn),
+            ((2017, 10, 28), DoubleNinth),
+            ((2018, 1, 24), Laba),
+        ];
+        for ((y, m, d), std) in dataset {
+            assert_eq!(Some(std), festival_for(g(y, m, d)), "{:?}", (y, m, d));
+        }
+        // 臘月小，除夕為廿九
+        let eve = g(2025, 1, 28);
+        assert_eq!(
+            Ok((2024, Month::Common(12), 29)),
+            Annus::from_date(eve).unwrap().ymd_for(eve)
+        );
+        assert_eq!(Some(NewYearsEve), festival_for(eve));
+        assert_eq!(None, festival_for(eve + -1));
+        // 臘月大，除夕為三十，廿九非除夕
+        let eve = g(2017, 1, 27);
+        assert_eq!(
+            Ok((2016, Month::Common(12), 30)),
+            Annus::from_date(eve).unwrap().ymd_for(eve)
+        );
+        assert_eq!(Some(NewYearsEve), festival_for(eve));
+        assert_eq!(None, festival_for(eve + -1));
+        // 閏五月初五不算端午
+        let date = g(2009, 6, 27);
+        assert_eq!(
+            Ok((2009, Month::Leap(5), 5)),
+            Annus::from_date(date).unwrap().ymd_for(date)
+        );
+        assert_eq!(None, festival_for(date));
+        assert_eq!(Some(DragonBoat), festival_for(g(2009, 5, 28)));
+
+        let annus = Annus::new(2017).unwrap();
+        let start = annus.months[0].date;
+        let days = annus.months.last().unwrap().date - start;
+        let found: Vec<_> = (0..days)
+            .map(|i| start + i)
+            .filter_map(|date| festival_for(date).map(|f| (date, f)))
+            .collect();
+        assert_eq!(annus.festival_dates(), found);
+        assert_eq!(None, festival_for(g(1000, 1, 1)));
+    }
+
+    #[test]
+    fn month_lengths() {
+        use Month::*;
+        let annus = Annus::new(2017).unwrap();
+        let dataset = [
+            (Common(11), 30),
+            (Common(12), 30),
+            (Common(1), 29),
+            (Common(2), 30),
+            (Common(3), 29),
+            (Common(4), 30),
+            (Common(5), 29),
+            (Common(6), 29),
+            (Leap(6), 30),
+            (Common(7), 29),
+            (Common(8), 30),
+            (Common(9), 29),
+            (Common(10), 30),
+        ];
+        let lengths: Vec<_> = annus
+            .month_lengths()
+            .map(|(m, len)| (m.month, len))
+            .collect();
+        assert_eq!(dataset.to_vec(), lengths);
+        for (month, std) in dataset {
+            assert_eq!(Some(std), annus.month_length(month), "{:?}", month);
+        }
+        assert_eq!(None, annus.month_length(Leap(7)));
+        assert_eq!(None, annus.month_length(Common(13)));
+
+        for y in ephemeris::ANNUS_RANGE {
+            let annus = Annus::new(y).unwrap();
+            let total: i32 = annus.month_lengths().map(|(_, len)| len).sum();
+            assert_eq!(
+                annus.months.last().unwrap().date - annus.months[0].date,
+                total
+            );
+            assert!(
+                annus.month_lengths().all(|(_, len)| len == 29 || len == 30),
+                "{}",
+                y
+            );
+            // 末月以次歲首月為界
+            let (last, len) = annus.month_lengths().last().unwrap();
+            assert_eq!(Some(len), annus.month_length(last.month));
+            if let Some(next) = Annus::new(y + 1) {
+                assert_eq!(next.months[0].date, last.date + len);
+            }
+        }
+    }
+
+    #[test]
+    fn dates_inverse() {
+        use LunarDateErr::*;
+        use Month::*;
+        let data = [
+            ((2016, Common(11), 1), Ok((2016, 11, 29))),
+            ((2016, Common(12), 30), Ok((2017, 1, 27))),
+            ((2017, Common(1), 1), Ok((2017, 1, 28))),
+            ((2017, Common(6), 29), Ok((2017, 7, 22))),
+            ((2017, Leap(6), 1), Ok((2017, 7, 23))),
+            ((2017, Common(10), 30), Ok((2017, 12, 17))),
+            ((2017, Common(6), 30), Err(NoSuchDay)),
+            ((2017, Common(1), 0), Err(NoSuchDay)),
+            ((2017, Leap(5), 1), Err(NoSuchMonth)),
+            (
+                (2016, Leap(6), 1),
+                Err(OtherAnnus(self::OtherAnnus::Before)),
+            ),
+            (
+                (2016, Common(10), 1),
+                Err(OtherAnnus(self::OtherAnnus::Before)),
+            ),
+            (
+                (2017, Common(11), 1),
+                Err(OtherAnnus(self::OtherAnnus::After)),
+            ),
+            ((2017, Common(13), 1), Err(NoSuchMonth)),
+        ];
+        let annus = Annus::new(2017).unwrap();
+        for ((y, m, d), std) in data {
+            let std = std.map(|(y, m, d)| Date::from_gregorian(y, m, d).unwrap());
+            assert_eq!(std, annus.date_for(y, m, d), "{:?}", (y, m, d));
+        }
+        for annus in 1975..=2049 {
+            let annus = Annus::new(annus).unwrap();
+            let end = annus.months.last().unwrap().date;
+            let mut date = annus.months[0].date;
+            while date < end {
+                let (y, m, d) = annus.ymd_for(date).unwrap();
+                assert_eq!(Ok(date), annus.date_for(y, m, d));
+                date = date + 1;
+            }
+        }
+    }
+
     #[test]
     fn solar_terms() {
         use self::OtherAnnus::*;
@@ -398,10 +2384,600 @@ mod tests {
         }
     }
 
+    #[test]
+    fn next_solar_terms() {
+        let annus = Annus::new(2017).unwrap();
+        let dataset = [
+            ((2016, 11, 29), (2016, 21, 8)),
+            ((2016, 12, 7), (2017, 22, 14)),
+            ((2016, 12, 20), (2017, 22, 1)),
+            ((2016, 12, 22), (2017, 23, 14)),
+            ((2017, 2, 10), (2017, 2, 8)),
+            ((2017, 12, 7), (2018, 22, 15)),
+            ((2017, 12, 21), (2018, 22, 1)),
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(Ok(std), annus.next_solar_term(date), "{:?}", (y, m, d));
+        }
+        assert!(annus
+            .next_solar_term(Date::from_gregorian(2017, 12, 22).unwrap())
+            .is_err());
+
+        let (start, end) = (annus.months[0].date, annus.term_dates[24]);
+        for date in (0..end - start).map(|i| start + i) {
+            let (_, term, days) = annus.solar_term_for(date).unwrap();
+            let (_, next_term, days_left) = annus.next_solar_term(date).unwrap();
+            assert_eq!(term % 24 + 1, next_term);
+            let term_start = date + -days;
+            let next_start = date + days_left;
+            assert!((14..=16).contains(&(next_start - term_start)), "{:?}", date);
+            assert_eq!(next_start - term_start, days + days_left);
+        }
+    }
+
+    #[test]
+    fn solar_terms_around() {
+        let g = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        let annus = Annus::new(2017).unwrap();
+        assert_eq!(
+            Some((22, g(2016, 12, 21))),
+            annus.solar_term_after(g(2016, 12, 7))
+        );
+        assert_eq!(
+            Some((21, g(2016, 12, 7))),
+            annus.solar_term_before(g(2016, 12, 8))
+        );
+        assert_eq!(
+            Some((24, g(2017, 1, 20))),
+            annus.solar_term_before(g(2017, 2, 3))
+        );
+        assert_eq!(
+            Some((1, g(2017, 2, 3))),
+            annus.solar_term_after(g(2017, 1, 20))
+        );
+        assert_eq!(
+            Some((22, g(2017, 12, 22))),
+            annus.solar_term_after(g(2017, 12, 7))
+        );
+        assert_eq!(None, annus.solar_term_after(g(2017, 12, 22)));
+        assert_eq!(None, annus.solar_term_after(g(2016, 11, 28)));
+        assert_eq!(
+            None,
+            annus.solar_term_before(annus.months.last().unwrap().date)
+        );
+
+        let (start, end) = (annus.months[0].date, annus.months.last().unwrap().date);
+        for date in (0..end - start).map(|i| start + i) {
+            let (next, next_date) = annus.solar_term_after(date).unwrap();
+            let (prev, prev_date) = annus.solar_term_before(date).unwrap();
+            assert!(prev_date < date && date < next_date);
+            // 交節當日前後兩節相隔一節
+            let step = if next_date - prev_date > 16 { 2 } else { 1 };
+            assert_eq!((prev + step - 1) % 24 + 1, next, "{:?}", date);
+            if date < annus.term_dates[24] {
+                let (_, term, days) = annus.next_solar_term(date).unwrap();
+                assert_eq!((term, date + days), (next, next_date));
+            }
+        }
+
+        // 曆表邊緣
+        let first = (1500..).find_map(Annus::new).unwrap();
+        let date = first.months[0].date;
+        assert_eq!(None, first.solar_term_before(date));
+        assert!(first.solar_term_after(date).is_some());
+    }
+
+    #[test]
+    fn solar_term_months() {
+        for y in [2000, 2014, 2017] {
+            let annus = Annus::new(y).unwrap();
+            assert_eq!(Some(Month::Common(11)), annus.solar_term_month(22));
+            let ws_day = annus.ymd_for(annus.term_dates[0]).unwrap().2;
+            assert_eq!(annus.winter_solstice_lunar_day(), ws_day);
+            for term in 1..=24 {
+                let month = annus.solar_term_month(term);
+                if term % 2 == 0 {
+                    // 中氣不在閏月
+                    assert!(!month.unwrap().is_leap(), "{} {}", y, term);
+                }
+            }
+        }
+        let annus = Annus::new(2017).unwrap();
+        assert_eq!(Some(Month::Common(6)), annus.solar_term_month(11)); // 小暑
+        assert_eq!(Some(Month::Leap(6)), annus.solar_term_month(13)); // 立秋
+        assert_eq!(Some(Month::Common(7)), annus.solar_term_month(14)); // 處暑
+    }
+
+    #[test]
+    fn solar_terms_conversions() {
+        let annus = Annus::new(2017).unwrap();
+        let (ws, end) = (annus.term_dates[0], annus.term_dates[24]);
+        // 冬至至次歲冬至間只讀已換算的交節日期：將其一律推遲一日，結果亦隨之推遲
+        let mut shifted = annus.clone();
+        for date in &mut shifted.term_dates {
+            *date = *date + 1;
+        }
+        let mut date = ws;
+        while date < end {
+            let std = annus.solar_term_for(date);
+            assert_eq!(std, shifted.solar_term_for(date + 1), "{:?}", date);
+            let (info, shifted_info) = (
+                annus.solar_term_detail(date).unwrap(),
+                shifted.solar_term_detail(date + 1).unwrap(),
+            );
+            assert_eq!(
+                (info.annus, info.term, info.days),
+                (shifted_info.annus, shifted_info.term, shifted_info.days)
+            );
+            date = date + 1;
+        }
+    }
+
+    #[test]
+    fn solar_term_details() {
+        let annus = Annus::new(2017).unwrap();
+        let mut date = annus.months[0].date;
+        while date < annus.term_dates[24] {
+            let (an, term, days) = annus.solar_term_for(date).unwrap();
+            let info = annus.solar_term_detail(date).unwrap();
+            assert_eq!((an, term, days), (info.annus, info.term.num(), info.days));
+            assert_eq!(days, (info.elapsed.floor() + 1.0) as i32, "{:?}", date);
+            assert_eq!(date + -days, date_cst(info.ingress));
+            assert_eq!(date + -days, info.ingress_cst.date);
+            assert_eq!(480, info.ingress_cst.tz_offset_minutes);
+            let secs = info.ingress_cst.seconds_of_day as f64;
+            assert!((days as f64 - secs / 86400.0 - info.elapsed).abs() < 1.0 / 86400.0);
+            date = date + 1;
+        }
+        for num in 1..=24 {
+            assert_eq!(num, SolarTerm::from_num(num).unwrap().num());
+        }
+        assert_eq!(None, SolarTerm::from_num(0));
+        assert_eq!(None, SolarTerm::from_num(25));
+        assert_eq!("清明", SolarTerm::QingMing.name());
+    }
+
+    #[test]
+    fn solar_longitude() {
+        let dataset = [
+            ((2016, 12, 7), 255.0),
+            ((2016, 12, 21), 270.0),
+            ((2017, 1, 20), 300.0),
+            ((2017, 2, 3), 315.0),
+            ((2017, 3, 20), 0.0),
+            ((2017, 12, 7), 255.0),
+        ];
+        let annus = Annus::new(2017).unwrap();
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(
+                Ok(std),
+                annus.solar_longitude_approx(date),
+                "{:?}",
+                (y, m, d)
+            );
+        }
+        let date = Date::from_gregorian(2017, 4, 1).unwrap();
+        let lon = annus.solar_longitude_approx(date).unwrap();
+        assert!((0.0..15.0).contains(&lon), "{}", lon);
+        assert_eq!(
+            Err(SolarTermErr::OtherAnnus(OtherAnnus::After)),
+            annus.solar_longitude_approx(Date::from_gregorian(2017, 12, 22).unwrap())
+        );
+    }
+
+    #[test]
+    fn month_from_str() {
+        for num in 1..=12 {
+            for month in [Month::Common(num), Month::Leap(num)] {
+                assert_eq!(Some(month), Month::from_str_cjk(&month.name()));
+            }
+        }
+        assert_eq!(Some(Month::Common(1)), Month::from_str_cjk("一月"));
+        assert_eq!(Some(Month::Leap(11)), Month::from_str_cjk("閏十一月"));
+        for s in [
+            "",
+            "月",
+            "閏月",
+            "正",
+            "十三月",
+            "閏閏五月",
+            "五月月",
+            "〇月",
+        ] {
+            assert_eq!(None, Month::from_str_cjk(s), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn month_display() {
+        use Month::*;
+        assert_eq!("閏正月", format!("{}", Leap(1)));
+        assert_eq!("臘月", format!("{}", Common(12)));
+        assert_eq!("閏0月", format!("{}", Leap(0)));
+        for m in 1..=12 {
+            assert_eq!(Common(m).name(), Common(m).to_string());
+            assert_eq!(Leap(m).name(), Leap(m).to_string());
+        }
+    }
+
+    #[test]
+    fn month_order() {
+        use Month::*;
+        assert!(Common(6) < Leap(6));
+        assert!(Leap(6) < Common(7));
+        assert!(Leap(1) > Common(1));
+        assert!(Common(12) > Common(11));
+        // 不處理跨年
+        assert!(Common(1) < Common(11));
+        assert!(Common(1) < Common(12));
+
+        // 除首尾冬月外，一歲之內月首日期與月名次序相同
+        for y in [2017, 2020, 2023, 2033] {
+            let annus = Annus::new(y).unwrap();
+            let months = &annus.months[..annus.months.len() - 1];
+            let (dec, rest) = months.split_at(2);
+            assert_eq!([Common(11), Common(12)], [dec[0].month, dec[1].month]);
+            assert!(rest.windows(2).all(|w| w[0].month < w[1].month), "{}", y);
+            let map: std::collections::BTreeMap<_, _> =
+                months.iter().map(|m| (m.month, m.date)).collect();
+            assert_eq!(months.len(), map.len());
+        }
+    }
+
+    #[test]
+    fn solar_term_map() {
+        let terms = solar_term_calendar(2016, 2018);
+        assert_eq!(24 * 3, terms.len());
+        for ((y, m, d), std) in [((2016, 12, 21), 22), ((2017, 2, 3), 1), ((2018, 12, 7), 21)] {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(Some(&std), terms.get(&date));
+        }
+        let mut prev = 21;
+        for &term in terms.values() {
+            assert_eq!(prev % 24 + 1, term);
+            prev = term;
+        }
+        assert!(solar_term_calendar(1000, 1001).is_empty());
+    }
+
+    #[test]
+    fn upcoming_terms() {
+        let g = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        let terms = upcoming_solar_terms(g(2016, 12, 10), 3);
+        assert_eq!(
+            vec![
+                (g(2016, 12, 21), 22),
+                (g(2017, 1, 5), 23),
+                (g(2017, 1, 20), 24)
+            ],
+            terms
+        );
+        let terms = upcoming_solar_terms(g(2016, 12, 21), 3);
+        assert_eq!((g(2017, 1, 5), 23), terms[0]);
+
+        let terms = upcoming_solar_terms(g(2016, 1, 1), 60);
+        assert_eq!(60, terms.len());
+        assert!(terms
+            .windows(2)
+            .all(|w| w[0].0 < w[1].0 && w[1].1 == w[0].1 % 24 + 1));
+        let map = solar_term_calendar(2016, 2019);
+        assert!(terms.iter().all(|(date, term)| map.get(date) == Some(term)));
+
+        assert!(upcoming_solar_terms(g(2016, 1, 1), 0).is_empty());
+        assert!(upcoming_solar_terms(g(3000, 1, 1), 3).is_empty());
+    }
+
+    #[test]
+    fn date_fractions() {
+        // TT - UTC = 69.184s in 2018
+        let tdb = Tdb::from_gregorian(2018, 1, 1, 16, 0, 69.184 + 10.0).unwrap();
+        let (date, fraction) = date_cst_with_fraction(tdb);
+        assert_eq!((2018, 1, 2), date.gregorian());
+        assert!((fraction - 10.0 / 86400.0).abs() < 1e-6, "{}", fraction);
+
+        let tdb = Tdb::from_gregorian(2018, 1, 1, 15, 59, 69.184 + 50.0).unwrap();
+        let (date, fraction) = date_cst_with_fraction(tdb);
+        assert_eq!((2018, 1, 1), date.gregorian());
+        assert!(
+            (fraction - (1.0 - 10.0 / 86400.0)).abs() < 1e-6,
+            "{}",
+            fraction
+        );
+
+        let tdb = Tdb::from_gregorian(2018, 1, 1, 4, 0, 69.184).unwrap();
+        let (date, fraction) = date_cst_with_fraction(tdb);
+        assert_eq!(
+            ((2018, 1, 1), 0.5),
+            (date.gregorian(), (fraction * 1e6).round() / 1e6)
+        );
+    }
+
+    #[test]
+    fn leap_patterns() {
+        let pattern = leap_pattern(2001).unwrap();
+        let leaps: Vec<_> = (2001..)
+            .zip(pattern)
+            .filter(|&(_, leap)| leap)
+            .map(|(y, _)| y)
+            .collect();
+        assert_eq!(vec![2001, 2004, 2006, 2009, 2012, 2014, 2017], leaps);
+        for y in 1950..=2082 {
+            let count = leap_pattern(y)
+                .unwrap()
+                .iter()
+                .filter(|&&leap| leap)
+                .count();
+            assert!((6..=8).contains(&count), "{}: {}", y, count);
+        }
+        assert_eq!(None, leap_pattern(2083));
+        assert_eq!(None, leap_pattern(1949));
+    }
+
+    #[test]
+    fn fu_and_jiu() {
+        let dataset = [
+            (
+                2020,
+                ["2020-07-16", "2020-07-26", "2020-08-15", "2020-08-25"],
+            ),
+            (
+                2021,
+                ["2021-07-11", "2021-07-21", "2021-08-10", "2021-08-20"],
+            ),
+            (
+                2023,
+                ["2023-07-11", "2023-07-21", "2023-08-10", "2023-08-20"],
+            ),
+        ];
+        for (year, std) in dataset {
+            let [chu, zhong, mo] = san_fu(year).unwrap();
+            assert_eq!(chu.1, zhong.0);
+            assert_eq!(zhong.1, mo.0);
+            let res = [chu.0, zhong.0, mo.0, mo.1].map(|d| d.iso_gregorian());
+            assert_eq!(std, res, "{}", year);
+            assert_eq!(7, chu.0.tian_gan_day());
+        }
+        assert_eq!(None, san_fu(1000));
+
+        let jiu = shu_jiu(2021).unwrap();
+        assert_eq!("2021-12-21", jiu[0].iso_gregorian());
+        assert_eq!(9, jiu[1] - jiu[0]);
+        assert_eq!(None, shu_jiu(1000));
+    }
+
+    #[test]
+    fn ganzhi_months() {
+        let dataset = [
+            ((2016, 11, 29), (2016, 10)),
+            ((2016, 12, 7), (2016, 11)),
+            ((2016, 12, 21), (2016, 11)),
+            ((2017, 1, 4), (2016, 11)),
+            ((2017, 1, 5), (2016, 12)),
+            ((2017, 2, 2), (2016, 12)),
+            ((2017, 2, 3), (2017, 1)),
+            ((2017, 3, 5), (2017, 2)),
+            ((2017, 12, 21), (2017, 11)),
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(Some(std), ganzhi_month_for(date), "{:?}", (y, m, d));
+        }
+    }
+
+    #[test]
+    fn jian_chu_days() {
+        let dataset = [
+            ((2017, 2, 2), 8),
+            ((2017, 2, 3), 8), // 立春
+            ((2017, 2, 8), 1),
+            ((2017, 2, 9), 2),
+            ((2017, 3, 4), 1),
+            ((2017, 3, 5), 1), // 驚蟄
+            ((2017, 3, 6), 2),
+            ((2016, 12, 22), 3), // 子月寅日
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(std, jian_chu(date).unwrap().0, "{:?}", (y, m, d));
+        }
+        assert_eq!(None, jian_chu(Date::from_gregorian(1000, 1, 1).unwrap()));
+    }
+
+    #[test]
+    fn sexagenary_arith() {
+        for (std, num, offset) in [
+            (1, 60, 1),
+            (60, 1, -1),
+            (1, 1, 60),
+            (1, 1, -60),
+            (17, 17, 600),
+            (7, 1, 66),
+            (55, 1, i32::MIN + 2),
+        ] {
+            assert_eq!(std, add_sexagenary(num, offset), "{} + {}", num, offset);
+        }
+        for (std, from, to) in [(0, 1, 1), (59, 2, 1), (2, 59, 1), (59, 1, 60)] {
+            assert_eq!(std, sexagenary_between(from, to));
+        }
+    }
+
+    #[test]
+    fn observances_for_dates() {
+        use self::Festival::*;
+        use Observance::{SiJue, SiLi, SolarTerm};
+        let f = Observance::Festival;
+        let dataset = [
+            ((2011, 2, 3), vec![f(SpringFestival), SiJue(1)]),
+            ((2011, 6, 6), vec![f(DragonBoat), SolarTerm(9)]),
+            ((2017, 1, 27), vec![f(NewYearsEve)]),
+            ((2017, 2, 2), vec![SiJue(1)]),
+            ((2017, 3, 10), vec![]),
+            ((2017, 3, 19), vec![SiLi(4)]),
+            ((2017, 4, 4), vec![SolarTerm(5)]),
+            ((2016, 12, 20), vec![SiLi(22)]),
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(std, observances(date), "{:?}", (y, m, d));
+        }
+        assert!(observances(Date::from_gregorian(1000, 1, 1).unwrap()).is_empty());
+        assert_eq!("清明", SolarTerm(5).name());
+        assert_eq!("除夕", f(NewYearsEve).name());
+    }
+
+    #[test]
+    fn year_sexagenary_for_dates() {
+        use YearBoundary::*;
+        let dataset = [
+            ((2016, 12, 20), 33, 33),
+            ((2017, 1, 27), 33, 33),
+            ((2017, 1, 28), 33, 34),
+            ((2017, 2, 2), 33, 34),
+            ((2017, 2, 3), 34, 34),
+            ((2017, 12, 31), 34, 34),
+        ];
+        for ((y, m, d), lichun, spring) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(
+                Some(lichun),
+                sexagenary_year_for_date(date, Lichun),
+                "{:?}",
+                (y, m, d)
+            );
+            assert_eq!(
+                Some(spring),
+                sexagenary_year_for_date(date, SpringFestival),
+                "{:?}",
+                (y, m, d)
+            );
+        }
+        let date = Date::from_gregorian(1000, 6, 1).unwrap();
+        assert_eq!(None, sexagenary_year_for_date(date, Lichun));
+        assert_eq!(None, sexagenary_year_for_date(date, SpringFestival));
+    }
+
+    #[test]
+    fn lunar_year_span() {
+        let (first, last) = lunar_year_gregorian_span(2017).unwrap();
+        assert_eq!((2017, 1, 28), first.gregorian());
+        assert_eq!((2018, 2, 15), last.gregorian());
+        assert_eq!(Some((2017, 2018)), lunar_year_gregorian_years(2017));
+        for year in 1951..2100 {
+            let (first, last) = lunar_year_gregorian_span(year).unwrap();
+            let annus = Annus::from_date(first).unwrap();
+            assert_eq!(Ok((year, Month::Common(1), 1)), annus.ymd_for(first));
+            let annus = Annus::from_date(last).unwrap();
+            assert_eq!(year, annus.ymd_for(last).unwrap().0);
+            assert_eq!(Some(last + 1), chinese_new_year(year + 1));
+            assert!((353..=385).contains(&(last - first + 1)), "{}", year);
+        }
+        let g = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        for (year, date) in [
+            (1950, g(1950, 2, 17)),
+            (1960, g(1960, 1, 28)),
+            (2080, g(2080, 1, 22)),
+        ] {
+            assert_eq!(Some(date), chinese_new_year(year));
+        }
+        assert_eq!(None, lunar_year_gregorian_span(1000));
+        assert_eq!(None, lunar_year_gregorian_years(i32::MAX));
+    }
+
+    #[test]
+    fn sexagenary_names() {
+        let names = sexagenary_cycle_names();
+        assert_eq!("甲子", names[0]);
+        assert_eq!("乙巳", names[41]);
+        assert_eq!("癸亥", names[59]);
+        let mut sorted = names.to_vec();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(60, sorted.len());
+    }
+
+    #[test]
+    fn lunar_mansions() {
+        let date = Date::from_gregorian(2020, 5, 5).unwrap();
+        assert_eq!((27, "翼"), lunar_mansion(date));
+        assert_eq!(lunar_mansion(date), lunar_mansion(date + 28));
+        assert_eq!(lunar_mansion(date), lunar_mansion(date + -28 * 100));
+        assert_eq!((1, "角"), lunar_mansion(date + 2));
+        // 角、斗、奎、井值木曜
+        for offset in 0..1000 {
+            let date = date + offset;
+            let (num, _) = lunar_mansion(date);
+            assert_eq!((num - 1) % 7, (date.day_of_week() - 4).rem_euclid(7));
+        }
+        assert_eq!((23, "鬼"), lunar_mansion(Date::from_jdn(2451551))); // 2000-01-07，甲子日
+        assert_eq!(lunar_mansion(Date::MIN), lunar_mansion(Date::MIN + 28));
+        assert_eq!(lunar_mansion(Date::MAX), lunar_mansion(Date::MAX + -28));
+    }
+
+    #[test]
+    fn zodiac() {
+        use YearBoundary::*;
+        assert_eq!(5, zodiac_for_year(2000));
+        assert_eq!(10, zodiac_for_year(2017));
+        assert_eq!(1, zodiac_for_year(2020));
+        assert_eq!(12, zodiac_for_year(2019));
+        assert_eq!(1, zodiac_for_year(-2696));
+        for year in -3000..3000 {
+            assert_eq!(zodiac_for_year(year) % 12 + 1, zodiac_for_year(year + 1));
+        }
+        let date = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        assert_eq!(Some(9), zodiac_for_date(date(2017, 1, 27), SpringFestival));
+        assert_eq!(Some(10), zodiac_for_date(date(2017, 1, 28), SpringFestival));
+        assert_eq!(Some(9), zodiac_for_date(date(2017, 2, 2), Lichun));
+        assert_eq!(Some(10), zodiac_for_date(date(2017, 2, 3), Lichun));
+        assert_eq!(None, zodiac_for_date(date(1000, 1, 1), SpringFestival));
+    }
+
     #[test]
     fn year_sexagenary() {
         for (std, year) in [(60, -2697), (1, -2696), (17, 2000)] {
             assert_eq!(std, sexagenary_for_year(year));
         }
     }
+
+    #[test]
+    fn ics_export() {
+        let annus = Annus::new(2017).unwrap();
+        // 2017-06-01T08:30:15Z
+        let ics = annus_to_ics(&annus, Ut(2457905.5 + 30615.5 / 86400.0));
+        let events = ics.matches("BEGIN:VEVENT\r\n").count();
+        assert_eq!(annus.months.len() - 1 + 24 + 9, events);
+        assert_eq!(events, ics.matches("DTSTAMP:20170601T083015Z\r\n").count());
+        assert!(ics.contains("UID:20170127-festival-除夕@kalendarilo\r\n"));
+        let uids: std::collections::BTreeSet<_> = ics
+            .split_terminator("\r\n")
+            .filter(|l| l.starts_with("UID:"))
+            .collect();
+        assert_eq!(events, uids.len());
+        // 與事件在列表中的位置無關
+        let later = annus_to_ics(&Annus::new(2018).unwrap(), Ut(2457905.5));
+        assert!(later.contains("UID:20180216-month-正月@kalendarilo\r\n"));
+        assert_eq!(events, ics.matches("END:VEVENT\r\n").count());
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains(
+            "DTSTART;VALUE=DATE:20170127\r\nDTEND;VALUE=DATE:20170128\r\nSUMMARY:除夕\r\n"
+        ));
+        assert!(ics.contains("SUMMARY:閏六月\r\n"));
+        for line in ics.split_terminator("\r\n") {
+            assert!(line.len() <= 75, "{:?}", line);
+            assert!(!line.contains('\n'));
+        }
+
+        let mut folded = String::new();
+        ics_line(&mut folded, &format!("SUMMARY:{}", "冬至".repeat(30)));
+        let lines: Vec<_> = folded.split_terminator("\r\n").collect();
+        assert!(lines.len() > 1 && lines.iter().all(|l| l.len() <= 75));
+        assert!(lines[1..].iter().all(|l| l.starts_with(' ')));
+        let unfolded: String = lines
+            .iter()
+            .map(|l| l.strip_prefix(' ').unwrap_or(l))
+            .collect();
+        assert_eq!(format!("SUMMARY:{}", "冬至".repeat(30)), unfolded);
+    }
 }
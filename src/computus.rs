@@ -0,0 +1,192 @@
+//! Computus, i.e. calculation of the date of Easter, and the moveable feasts
+//! depending on it.
+
+use crate::date::Date;
+
+/// Returns the date of Easter Sunday in year `year` by the Julian computus
+/// (as still used by most Eastern churches), expressed in (proleptic) Julian
+/// calendar, in `(year, month, day)` format.
+///
+/// `year` should be an astronomical year number, see
+/// [`Date::from_julian`].
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::computus::julian_easter_julian;
+///
+/// assert_eq!((2024, 4, 22), julian_easter_julian(2024)); // May 5 in Gregorian calendar
+/// ```
+pub fn julian_easter_julian(year: i32) -> (i32, i32, i32) {
+    // Meeus' Julian algorithm
+    let a = year.rem_euclid(4);
+    let b = year.rem_euclid(7);
+    let c = year.rem_euclid(19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let f = d + e + 114;
+    (year, f / 31, f % 31 + 1)
+}
+
+/// Same as [`julian_easter_julian`], but returns a `Date`.
+///
+/// Returns `None` if the date is out of supported range.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::computus::easter_julian;
+///
+/// let date = easter_julian(2024).unwrap();
+/// assert_eq!((2024, 5, 5), date.gregorian());
+/// ```
+pub fn easter_julian(year: i32) -> Option<Date> {
+    let (y, m, d) = julian_easter_julian(year);
+    Date::from_julian(y, m, d)
+}
+
+/// Returns the date of Easter Sunday in year `year` by the Gregorian computus
+/// (as used by Western churches), expressed in (proleptic) Gregorian
+/// calendar, in `(year, month, day)` format.
+///
+/// `year` should be an astronomical year number, see
+/// [`Date::from_gregorian`].
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::computus::gregorian_easter_gregorian;
+///
+/// assert_eq!((2024, 3, 31), gregorian_easter_gregorian(2024));
+/// ```
+pub fn gregorian_easter_gregorian(year: i32) -> (i32, i32, i32) {
+    // Anonymous Gregorian algorithm
+    let a = year.rem_euclid(19);
+    let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+    (year, n / 31, n % 31 + 1)
+}
+
+/// Same as [`gregorian_easter_gregorian`], but returns a `Date`.
+///
+/// Returns `None` if the date is out of supported range.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::computus::easter_gregorian;
+///
+/// let date = easter_gregorian(2000).unwrap();
+/// assert_eq!((2000, 4, 23), date.gregorian());
+/// ```
+pub fn easter_gregorian(year: i32) -> Option<Date> {
+    let (y, m, d) = gregorian_easter_gregorian(year);
+    Date::from_gregorian(y, m, d)
+}
+
+/// Returns the date of Ash Wednesday, 46 days before Easter Sunday (see
+/// [`easter_gregorian`]), in year `year` by the Gregorian computus.
+///
+/// Returns `None` if the date is out of supported range.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::computus::ash_wednesday;
+///
+/// assert_eq!((2024, 2, 14), ash_wednesday(2024).unwrap().gregorian());
+/// ```
+pub fn ash_wednesday(year: i32) -> Option<Date> {
+    // In range whenever Easter is, as the range starts in November
+    Some(easter_gregorian(year)? + -46)
+}
+
+/// Returns the date of Pentecost, 49 days after Easter Sunday (see
+/// [`easter_gregorian`]), in year `year` by the Gregorian computus.
+///
+/// Returns `None` if the date is out of supported range.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::computus::pentecost;
+///
+/// assert_eq!((2024, 5, 19), pentecost(2024).unwrap().gregorian());
+/// ```
+pub fn pentecost(year: i32) -> Option<Date> {
+    // In range whenever Easter is, as the range ends in December
+    Some(easter_gregorian(year)? + 49)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn julian_easter() {
+        let dataset = [
+            (2000, (4, 17), (4, 30)),
+            (2008, (4, 14), (4, 27)),
+            (2024, (4, 22), (5, 5)),
+            (2025, (4, 7), (4, 20)),
+        ];
+        for (year, (jm, jd), (gm, gd)) in dataset {
+            assert_eq!((year, jm, jd), julian_easter_julian(year));
+            let date = easter_julian(year).unwrap();
+            assert_eq!((year, gm, gd), date.gregorian());
+            assert_eq!((year, jm, jd), date.julian());
+            assert_eq!(7, date.day_of_week());
+        }
+        for year in -4712..3000 {
+            let (_, m, d) = julian_easter_julian(year);
+            assert!((3, 22) <= (m, d) && (m, d) <= (4, 25), "{}", year);
+            assert_eq!(7, easter_julian(year).unwrap().day_of_week(), "{}", year);
+        }
+        assert_eq!(None, easter_julian(-4713));
+    }
+    #[test]
+    fn gregorian_easter() {
+        let dataset = [
+            (1961, (4, 2)),
+            (2000, (4, 23)),
+            (2008, (3, 23)),
+            (2011, (4, 24)),
+            (2024, (3, 31)),
+            (2025, (4, 20)),
+            (2285, (3, 22)),
+            (2038, (4, 25)),
+        ];
+        for (year, (m, d)) in dataset {
+            assert_eq!((year, m, d), gregorian_easter_gregorian(year));
+            let date = easter_gregorian(year).unwrap();
+            assert_eq!((year, m, d), date.gregorian());
+            assert_eq!(Some(date + -46), ash_wednesday(year));
+            assert_eq!(Some(date + 49), pentecost(year));
+        }
+        // Coincides with Julian Easter in some years
+        assert_eq!(easter_gregorian(2025), easter_julian(2025));
+        let years = (-4712..3000).chain(11754000..=11754508);
+        for year in years {
+            let (_, m, d) = gregorian_easter_gregorian(year);
+            assert!((3, 22) <= (m, d) && (m, d) <= (4, 25), "{}", year);
+            let date = easter_gregorian(year).unwrap();
+            assert_eq!(7, date.day_of_week(), "{}", year);
+            assert_eq!(3, ash_wednesday(year).unwrap().day_of_week());
+            assert_eq!(7, pentecost(year).unwrap().day_of_week());
+        }
+        for year in [-4713, 11754509, i32::MIN, i32::MAX] {
+            assert_eq!(None, easter_gregorian(year));
+            assert_eq!(None, ash_wednesday(year));
+            assert_eq!(None, pentecost(year));
+        }
+        let (_, m, d) = gregorian_easter_gregorian(i32::MIN);
+        assert!((3, 22) <= (m, d) && (m, d) <= (4, 25));
+    }
+}
@@ -2,15 +2,41 @@
 
 use std::ops::{Add, Sub};
 
+/// JDN of the Unix epoch, 1970-01-01.
+const UNIX_EPOCH_JDN: i64 = 2440588;
+
 /// A calendar-independant date.
 ///
-/// Supported range begins from January 1, 4713 BC, proleptic Julian calendar.
+/// Supported range begins from January 1, 4713 BC, proleptic Julian calendar,
+/// see [`Date::MIN`] and [`Date::MAX`].
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Date {
     jdn: u32,
 }
 
 impl Date {
+    /// The earliest supported date, January 1, 4713 BC in proleptic Julian
+    /// calendar (or November 24, 4714 BC in proleptic Gregorian calendar), with
+    /// JDN 0.
+    pub const MIN: Date = Date { jdn: 0 };
+    /// The latest supported date, December 13, 11754508 in proleptic Gregorian
+    /// calendar, with JDN `u32::MAX`.
+    pub const MAX: Date = Date { jdn: u32::MAX };
+
+    /// Returns `true` if `jdn` is in supported range, i.e. between the JDNs of
+    /// [`Date::MIN`] and [`Date::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert!(Date::is_jdn_in_range(0));
+    /// assert!(!Date::is_jdn_in_range(-1));
+    /// ```
+    pub fn is_jdn_in_range(jdn: i64) -> bool {
+        (i64::from(Self::MIN.jdn)..=i64::from(Self::MAX.jdn)).contains(&jdn)
+    }
     /// Creates a `Date` with a Julian day number (JDN).
     pub fn from_jdn(jdn: u32) -> Self {
         Self { jdn }
@@ -19,13 +45,227 @@ impl Date {
     pub fn jdn(&self) -> u32 {
         self.jdn
     }
+    /// Sets the Julian day number (JDN) of the date in place.
+    pub fn set_jdn(&mut self, jdn: u32) {
+        self.jdn = jdn;
+    }
+    /// Moves the date forward (or backward if `days` is negative) in place,
+    /// same as `*self = *self + days`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let mut date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// date.advance(31);
+    /// assert_eq!((2000, 2, 1), date.gregorian());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like `Add<i32>`, panics in debug mode (and wraps around in release mode)
+    /// if the result is out of supported range.
+    pub fn advance(&mut self, days: i32) {
+        *self = *self + days;
+    }
+    /// Returns the number of days from `rhs` to `self` (negative if `self` is
+    /// earlier), or `None` if it does not fit in an `i32`.
+    ///
+    /// Unlike `Sub<Date>`, which wraps around for large spans, this never
+    /// returns a wrong result. See [`days_since`](Self::days_since) for a
+    /// version that covers the full range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let a = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// let b = Date::from_gregorian(2000, 3, 1).unwrap();
+    /// assert_eq!(Some(60), b.checked_signed_diff(a));
+    /// assert_eq!(Some(-60), a.checked_signed_diff(b));
+    /// assert_eq!(None, Date::MAX.checked_signed_diff(Date::MIN));
+    /// ```
+    pub fn checked_signed_diff(&self, rhs: Date) -> Option<i32> {
+        i32::try_from(self.days_since(rhs)).ok()
+    }
+    /// Returns the number of days from `rhs` to `self` (negative if `self` is
+    /// earlier), same as [`days_since`](Self::days_since).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert_eq!(u32::MAX as i64, Date::MAX.signed_diff_i64(Date::MIN));
+    /// ```
+    pub fn signed_diff_i64(&self, rhs: Date) -> i64 {
+        self.days_since(rhs)
+    }
+    /// Returns the number of days from `other` to `self`, negative if `self`
+    /// is earlier.
+    ///
+    /// Never panics or overflows, as any two dates are less than `2^32` days
+    /// apart. This is the recommended way to compute intervals that may be
+    /// negative or span the full supported range, where `Sub<Date>` would
+    /// overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let a = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// let b = Date::from_gregorian(2000, 3, 1).unwrap();
+    /// assert_eq!(60, b.days_since(a));
+    /// assert_eq!(-60, a.days_since(b));
+    /// assert_eq!(-(u32::MAX as i64), Date::MIN.days_since(Date::MAX));
+    /// ```
+    pub fn days_since(&self, other: Date) -> i64 {
+        i64::from(self.jdn) - i64::from(other.jdn)
+    }
+    /// Returns the span from `earlier` to `self` as a [`Duration`] of whole
+    /// days (86400 seconds each), or `None` if `self` is earlier than
+    /// `earlier`.
+    ///
+    /// [`Duration`]: std::time::Duration
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let a = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!(86400 * 10, (a + 10).duration_since(a).unwrap().as_secs());
+    /// assert_eq!(None, a.duration_since(a + 10));
+    /// ```
+    pub fn duration_since(&self, earlier: Date) -> Option<std::time::Duration> {
+        let days = self.jdn.checked_sub(earlier.jdn)?;
+        Some(std::time::Duration::from_secs(u64::from(days) * 86400))
+    }
+    /// Returns the current date from the system clock, in timezone ahead
+    /// (east) of UTC by `tz_offset_minutes` minutes.
+    ///
+    /// For Beijing time (UTC+8), `tz_offset_minutes` should be +480.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let today = Date::today(480);
+    /// assert!(today.gregorian().0 >= 2000);
+    /// ```
+    #[cfg(feature = "clock")]
+    pub fn today(tz_offset_minutes: i32) -> Self {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let secs = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64) - 1,
+        };
+        Self::from_unix_secs(secs, tz_offset_minutes)
+    }
+    /// Returns the number of days from today (see [`today`](Self::today)) to
+    /// `self`, negative if `self` is in the past.
+    ///
+    /// Like [`days_since`](Self::days_since), this covers the full supported
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let today = Date::today(480);
+    /// let days = (today + 7).days_from_today(480);
+    /// assert!(days == 7 || days == 6); // may cross midnight in between
+    /// ```
+    #[cfg(feature = "clock")]
+    pub fn days_from_today(&self, tz_offset_minutes: i32) -> i64 {
+        self.days_since(Self::today(tz_offset_minutes))
+    }
+    /// Creates a `Date` from the number of days since the Unix epoch
+    /// (1970-01-01, JDN 2440588), or `None` if out of supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert_eq!((1970, 1, 1), Date::from_unix_days(0).unwrap().gregorian());
+    /// assert_eq!((1969, 12, 31), Date::from_unix_days(-1).unwrap().gregorian());
+    /// assert_eq!(None, Date::from_unix_days(i64::MIN));
+    /// ```
+    pub fn from_unix_days(days: i64) -> Option<Self> {
+        let jdn = days.checked_add(UNIX_EPOCH_JDN)?;
+        Self::is_jdn_in_range(jdn).then(|| Self::from_jdn(jdn as u32))
+    }
+    /// Returns the number of days since the Unix epoch (1970-01-01), negative
+    /// for earlier dates. Inverse of [`from_unix_days`](Self::from_unix_days).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert_eq!(10957, Date::from_gregorian(2000, 1, 1).unwrap().unix_days());
+    /// ```
+    pub fn unix_days(&self) -> i64 {
+        i64::from(self.jdn) - UNIX_EPOCH_JDN
+    }
+    /// Creates a `Date` from a Unix timestamp (seconds since
+    /// 1970-01-01T00:00:00Z), taking the UTC day it falls in, or `None` if out
+    /// of supported range.
+    ///
+    /// Negative timestamps are floored, so `-1` is on 1969-12-31.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert_eq!((1970, 1, 2), Date::from_unix_timestamp(86400).unwrap().gregorian());
+    /// assert_eq!((1969, 12, 31), Date::from_unix_timestamp(-1).unwrap().gregorian());
+    /// ```
+    pub fn from_unix_timestamp(secs: i64) -> Option<Self> {
+        Self::from_unix_days(secs.div_euclid(86400))
+    }
+    /// The date at `secs` seconds since the Unix epoch, in given timezone.
+    #[cfg(feature = "clock")]
+    fn from_unix_secs(secs: i64, tz_offset_minutes: i32) -> Self {
+        let days = (secs + i64::from(tz_offset_minutes) * 60).div_euclid(86400);
+        Self::from_jdn((UNIX_EPOCH_JDN + days) as u32)
+    }
+    /// Creates a `Date` from its packed form, see [`to_packed`](Self::to_packed).
+    pub fn from_packed(bytes: [u8; 4]) -> Self {
+        Self::from_jdn(u32::from_be_bytes(bytes))
+    }
+    /// Packs the date into 4 bytes (the JDN in big-endian order), e.g. for
+    /// compact binary storage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!([0x00, 0x25, 0x68, 0x59], date.to_packed());
+    /// assert_eq!(date, Date::from_packed(date.to_packed()));
+    /// ```
+    pub fn to_packed(&self) -> [u8; 4] {
+        self.jdn.to_be_bytes()
+    }
 
     /// Creates a `Date` with a Gregorian calendar date.
     ///
     /// `year` should be an astronomical year number, i.e. 1 BC is `0`, 2
     /// BC is `-1`, etc.
     ///
-    /// Returns `None` if the result date is out of supported range.
+    /// Returns `None` if the date does not exist, e.g. February 30 or month
+    /// 13, or the result date is out of supported range. Use
+    /// [`from_gregorian_lenient`](Self::from_gregorian_lenient) to roll such
+    /// dates over instead.
     ///
     /// # Example
     ///
@@ -34,17 +274,10 @@ impl Date {
     ///
     /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
     /// assert_eq!(2451545, date.jdn());
+    /// assert_eq!(None, Date::from_gregorian(2021, 2, 29));
     /// ```
     pub fn from_gregorian(year: i32, month: i32, day: i32) -> Option<Self> {
-        let (y, m, d) = (year, month, day);
-        u32::try_from(
-            (1461 * (y + 4800 + (m - 14) / 12)) / 4 + (367 * (m - 2 - 12 * ((m - 14) / 12))) / 12
-                - (3 * ((y + 4900 + (m - 14) / 12) / 100)) / 4
-                + d
-                - 32075,
-        )
-        .map(Self::from_jdn)
-        .ok()
+        Self::from_gregorian_i64(year.into(), month, day)
     }
     /// Represents the date in Gregorian calendar.
     ///
@@ -59,7 +292,164 @@ impl Date {
     /// assert_eq!((2000, 1, 1), date.gregorian());
     /// ```
     pub fn gregorian(&self) -> (i32, i32, i32) {
-        let jdn = i32::try_from(self.jdn).expect("jdn >= 2**31 not supported");
+        let (year, month, day) = self.gregorian_i64();
+        // Years in the supported range never exceed 11754508
+        (year as i32, month, day)
+    }
+    /// Creates a `Date` with a Gregorian calendar date, with the year given in
+    /// BC/AD style, e.g. 1 BC is `(Era::Bce, 1)`, i.e. astronomical year `0`.
+    ///
+    /// Returns `None` if `year` is not positive, or the result date is out of
+    /// supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::{Date, Era};
+    ///
+    /// let date = Date::from_gregorian_era(Era::Bce, 1, 1, 1).unwrap();
+    /// assert_eq!((0, 1, 1), date.gregorian());
+    /// ```
+    pub fn from_gregorian_era(era: Era, year: i32, month: i32, day: i32) -> Option<Self> {
+        if year < 1 {
+            return None;
+        }
+        let year = match era {
+            Era::Bce => 1 - year,
+            Era::Ce => year,
+        };
+        Self::from_gregorian(year, month, day)
+    }
+    /// Represents the date in Gregorian calendar with the year in BC/AD style,
+    /// in `(era, year, month, day)` format, see
+    /// [`from_gregorian_era`](Self::from_gregorian_era).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::{Date, Era};
+    ///
+    /// let date = Date::from_gregorian(-43, 3, 15).unwrap();
+    /// assert_eq!((Era::Bce, 44, 3, 15), date.gregorian_era());
+    /// ```
+    pub fn gregorian_era(&self) -> (Era, i32, i32, i32) {
+        let (y, m, d) = self.gregorian();
+        if y < 1 {
+            (Era::Bce, 1 - y, m, d)
+        } else {
+            (Era::Ce, y, m, d)
+        }
+    }
+    /// Same as [`gregorian`](Self::gregorian), but returns a struct with named
+    /// fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::{Date, GregorianDate};
+    ///
+    /// let date = Date::from_jdn(2451545);
+    /// let parts = date.to_gregorian_parts();
+    /// assert_eq!(GregorianDate { year: 2000, month: 1, day: 1 }, parts);
+    /// assert_eq!(date.gregorian(), parts.into());
+    /// ```
+    pub fn to_gregorian_parts(&self) -> GregorianDate {
+        self.gregorian().into()
+    }
+    /// Returns the Gregorian date, the day of year and the day of week at once,
+    /// decomposing the date only once, for bulk processing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::{Date, DateParts};
+    ///
+    /// let date = Date::from_gregorian(2000, 3, 1).unwrap();
+    /// let parts = date.decompose();
+    /// assert_eq!((2000, 3, 1), (parts.year, parts.month, parts.day));
+    /// assert_eq!(61, parts.ordinal);
+    /// assert_eq!(3, parts.weekday); // Wednesday
+    /// ```
+    pub fn decompose(&self) -> DateParts {
+        let (year, month, day) = self.gregorian();
+        DateParts {
+            year,
+            month,
+            day,
+            ordinal: ordinal_day_number(month, day, YearType::from_gregorian(year)),
+            weekday: self.day_of_week(),
+        }
+    }
+    /// Same as [`from_gregorian`](Self::from_gregorian), but with an `i64`
+    /// year, so that the whole supported range, which ends in year 11754508,
+    /// is reachable without overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian_i64(11754508, 12, 13).unwrap();
+    /// assert_eq!(u32::MAX, date.jdn());
+    /// ```
+    pub fn from_gregorian_i64(year: i64, month: i32, day: i32) -> Option<Self> {
+        // Leap years repeat every 400 years
+        let year_in_cycle = year.rem_euclid(400) as i32;
+        if !(1..=12).contains(&month) || !(1..=days_in_month(year_in_cycle, month)).contains(&day) {
+            return None;
+        }
+        let jdn = gregorian_jdn(year, month.into(), day.into());
+        Some(Self::from_jdn(jdn as u32)).filter(|_| Self::is_jdn_in_range(jdn))
+    }
+    /// Creates a `Date` with a Gregorian calendar date, normalizing
+    /// out-of-range months and days by rolling over into adjacent months and
+    /// years, e.g. January 32 is February 1, and month 0 is December of the
+    /// previous year.
+    ///
+    /// Returns `None` only if the result date is out of supported range. Use
+    /// [`from_gregorian`](Self::from_gregorian) to reject such dates instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian_lenient(2000, 1, 32).unwrap();
+    /// assert_eq!((2000, 2, 1), date.gregorian());
+    /// assert_eq!(None, Date::from_gregorian(2000, 1, 32));
+    /// ```
+    pub fn from_gregorian_lenient(year: i32, month: i32, day: i32) -> Option<Self> {
+        let m0 = i64::from(month) - 1;
+        let (y, m) = (i64::from(year) + m0.div_euclid(12), m0.rem_euclid(12) + 1);
+        let jdn = gregorian_jdn(y, m, 1) + i64::from(day) - 1;
+        Some(Self::from_jdn(jdn as u32)).filter(|_| Self::is_jdn_in_range(jdn))
+    }
+    /// Creates a `Date` with a Gregorian calendar date, normalizing an
+    /// out-of-range `month_offset` into the adjacent years, e.g. month 0 is
+    /// December of the previous year, and month 13 is January of the next
+    /// year.
+    ///
+    /// Unlike [`from_gregorian_lenient`](Self::from_gregorian_lenient), the
+    /// day is not rolled over, and `None` is returned if `day` is not in the
+    /// normalized month, or if the date is out of supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian_normalized(2000, 0, 31).unwrap();
+    /// assert_eq!((1999, 12, 31), date.gregorian());
+    /// assert_eq!(None, Date::from_gregorian_normalized(2001, 14, 29));
+    /// ```
+    pub fn from_gregorian_normalized(year: i32, month_offset: i32, day: i32) -> Option<Self> {
+        let m0 = i64::from(month_offset) - 1;
+        let year = i32::try_from(i64::from(year) + m0.div_euclid(12)).ok()?;
+        Self::from_gregorian(year, m0.rem_euclid(12) as i32 + 1, day)
+    }
+    /// Same as [`gregorian`](Self::gregorian), but returns an `i64` year.
+    pub fn gregorian_i64(&self) -> (i64, i32, i32) {
+        let jdn = i64::from(self.jdn);
         let f = jdn + 1401 + (((4 * jdn + 274277) / 146097) * 3) / 4 - 38;
         let e = 4 * f + 3;
         let g = (e % 1461) / 4;
@@ -67,39 +457,109 @@ impl Date {
         let day = (h % 153) / 5 + 1;
         let month = (h / 153 + 2) % 12 + 1;
         let year = e / 1461 - 4716 + (12 + 2 - month) / 12;
-        (year, month, day)
+        (year, month as i32, day as i32)
     }
-    /// Formats the date in ISO 8601 format.
+    /// Returns the Gregorian year and the day of year (starting from 1) of the
+    /// date, in `(year, ordinal)` format, as in ISO 8601 ordinal dates.
     ///
     /// # Example
     ///
     /// ```
     /// use kalendarilo::Date;
     ///
-    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
-    /// assert_eq!("2000-01-01", date.iso_gregorian());
+    /// let date = Date::from_gregorian(2000, 12, 31).unwrap();
+    /// assert_eq!((2000, 366), date.to_ordinal_parts());
     /// ```
-    pub fn iso_gregorian(&self) -> String {
+    pub fn to_ordinal_parts(&self) -> (i32, i32) {
         let (y, m, d) = self.gregorian();
-        format!("{:04}-{:02}-{:02}", y, m, d)
+        (y, ordinal_day_number(m, d, YearType::from_gregorian(y)))
     }
-
-    /// Returns the day of week of the date, in ISO-8601 numbering (i.e.
-    /// `1..=7` for Monday through Sunday)
+    /// Creates a `Date` with a Gregorian year and a day of year, the inverse
+    /// of [`to_ordinal_parts`](Self::to_ordinal_parts).
+    ///
+    /// Returns `None` if `ordinal` is not in the year, or the result date is
+    /// out of supported range.
     ///
     /// # Example
     ///
     /// ```
     /// use kalendarilo::Date;
     ///
-    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
-    /// assert_eq!(6, date.day_of_week()); // Saturday
+    /// let date = Date::from_ordinal_parts((2000, 60)).unwrap();
+    /// assert_eq!((2000, 2, 29), date.gregorian());
+    /// assert_eq!(None, Date::from_ordinal_parts((2001, 366)));
     /// ```
-    pub fn day_of_week(&self) -> i32 {
-        (self.jdn % 7 + 1) as i32
+    pub fn from_ordinal_parts((year, ordinal): (i32, i32)) -> Option<Self> {
+        Self::from_gregorian_ordinal(year, ordinal)
     }
-    /// Returns the Chinese sexagenary day number of the date, numbered from 1
-    /// (甲子) to 60 (癸亥).
+    /// Returns the day of year of the date in Gregorian calendar, starting
+    /// from 1, so that December 31 is 365 or 366.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(1900, 3, 1).unwrap();
+    /// assert_eq!(60, date.ordinal_gregorian()); // 1900 is a common year
+    /// ```
+    pub fn ordinal_gregorian(&self) -> i32 {
+        self.to_ordinal_parts().1
+    }
+    /// Creates a `Date` with a Gregorian year and a day of year starting from
+    /// 1, the inverse of [`ordinal_gregorian`](Self::ordinal_gregorian).
+    ///
+    /// Returns `None` if `ordinal` is not in the year, e.g. 366 in a common
+    /// year, or the result date is out of supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian_ordinal(2000, 366).unwrap();
+    /// assert_eq!((2000, 12, 31), date.gregorian());
+    /// assert_eq!(None, Date::from_gregorian_ordinal(1900, 366));
+    /// ```
+    pub fn from_gregorian_ordinal(year: i32, ordinal: i32) -> Option<Self> {
+        let days = 365 + YearType::from_gregorian(year).is_leap() as i32;
+        if !(1..=days).contains(&ordinal) {
+            return None;
+        }
+        Self::from_gregorian_lenient(year, 1, ordinal)
+    }
+    /// Creates a `Date` with a (proleptic) Julian calendar date.
+    ///
+    /// `year` should be an astronomical year number as in
+    /// [`from_gregorian`](Self::from_gregorian).
+    ///
+    /// Returns `None` if the date does not exist in Julian calendar (e.g.
+    /// February 30 or month 13), or is out of supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_julian(1999, 12, 19).unwrap();
+    /// assert_eq!((2000, 1, 1), date.gregorian());
+    /// assert!(Date::from_julian(1900, 2, 29).is_some()); // Leap year in Julian calendar
+    /// assert_eq!(None, Date::from_julian(2021, 2, 30));
+    /// ```
+    pub fn from_julian(year: i32, month: i32, day: i32) -> Option<Self> {
+        let year_type = YearType::from_julian(year);
+        if !(1..=12).contains(&month) || !(1..=month_length(month, year_type)).contains(&day) {
+            return None;
+        }
+        let a = (14 - month) / 12;
+        let y = i64::from(year) + 4800 - i64::from(a);
+        let m = i64::from(month + 12 * a - 3);
+        let jdn = i64::from(day) + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+        Some(Self::from_jdn(jdn as u32)).filter(|_| Self::is_jdn_in_range(jdn))
+    }
+    /// Represents the date in (proleptic) Julian calendar.
+    ///
+    /// Returns in `(year, month, day)` format.
     ///
     /// # Example
     ///
@@ -107,45 +567,1011 @@ impl Date {
     /// use kalendarilo::Date;
     ///
     /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
-    /// assert_eq!(55, date.sexagenary()); // 戊午
+    /// assert_eq!((1999, 12, 19), date.julian());
     /// ```
-    pub fn sexagenary(&self) -> i32 {
-        ((self.jdn + 49) % 60 + 1) as i32
+    pub fn julian(&self) -> (i32, i32, i32) {
+        let c = i64::from(self.jdn) + 32082;
+        let d = (4 * c + 3) / 1461;
+        let e = c - 1461 * d / 4;
+        let m = (5 * e + 2) / 153;
+        let day = e - (153 * m + 2) / 5 + 1;
+        let month = m + 3 - 12 * (m / 10);
+        let year = d - 4800 + m / 10;
+        // Years in the supported range never exceed 11754267
+        (year as i32, month as i32, day as i32)
     }
 
-    /// Returns the ISO-8601 week number (with the year of that week) of the
-    /// date, in `(year, week)` format.
+    /// Creates a `Date` with a date in the historical Western calendar, i.e.
+    /// Julian calendar before October 15, 1582 and Gregorian calendar since
+    /// then, with `year` as in [`from_gregorian`](Self::from_gregorian).
+    ///
+    /// Returns `None` for invalid dates, including October 5 to 14, 1582,
+    /// which were skipped at the cutover, and for dates out of supported
+    /// range.
+    ///
+    /// # Example
     ///
     /// ```
     /// use kalendarilo::Date;
     ///
-    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
-    /// assert_eq!((1999, 52), date.year_week_gregorian()); // 1999-W52-6
+    /// let last_julian = Date::from_historical(1582, 10, 4).unwrap();
+    /// let first_gregorian = Date::from_historical(1582, 10, 15).unwrap();
+    /// assert_eq!(1, first_gregorian - last_julian);
+    /// assert_eq!(None, Date::from_historical(1582, 10, 10));
     /// ```
-    pub fn year_week_gregorian(&self) -> (i32, i32) {
-        let (y, m, d) = self.gregorian();
-        let y_type = YearType::from_gregorian(y);
-        let y_is_leap = y_type.is_leap() as i32;
-        let dn = ordinal_day_number(m, d, y_type);
-        let dow = self.day_of_week();
-        let dow1 = (dow - dn).rem_euclid(7) + 1;
-        if dow1 > 4 && dow1 - 1 + dn <= 7 {
-            use std::cmp::Ordering::*;
-            return match dow1.cmp(&6) {
-                Less => (y - 1, 53),
-                Equal => (y - 1, 52 + YearType::from_gregorian(y - 1).is_leap() as i32),
-                Greater => (y - 1, 52),
-            };
+    pub fn from_historical(year: i32, month: i32, day: i32) -> Option<Self> {
+        if (year, month, day) >= (1582, 10, 15) {
+            Self::from_gregorian(year, month, day)
+        } else if (year, month, day) < (1582, 10, 5) {
+            Self::from_julian(year, month, day)
+        } else {
+            None
         }
-        let dow_last = (dow1 + 364 + y_is_leap - 1).rem_euclid(7) + 1;
-        if dow_last < 4 && 365 + y_is_leap + 1 - dn <= dow_last {
-            return (y + 1, 1);
+    }
+
+    /// Returns the day of year of the date in (proleptic) Julian calendar,
+    /// starting from 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_julian(1900, 3, 1).unwrap();
+    /// assert_eq!(61, date.ordinal_julian()); // 1900 is a leap year in Julian calendar
+    /// ```
+    pub fn ordinal_julian(&self) -> i32 {
+        let (y, m, d) = self.julian();
+        ordinal_day_number(m, d, YearType::from_julian(y))
+    }
+
+    /// Formats the date in ISO 8601 format, same as [`Display`].
+    ///
+    /// Years outside `0..=9999` are written in the expanded form with a sign
+    /// and 6 digits, e.g. `-000044-12-31` for December 31, 45 BC
+    /// (astronomical year -44).
+    ///
+    /// [`Display`]: std::fmt::Display
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!("2000-01-01", date.iso_gregorian());
+    /// ```
+    pub fn iso_gregorian(&self) -> String {
+        self.to_string()
+    }
+    /// Formats the date in the same format as
+    /// [`iso_gregorian`](Self::iso_gregorian), but in (proleptic) Julian
+    /// calendar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!("1999-12-19", date.iso_julian());
+    /// ```
+    pub fn iso_julian(&self) -> String {
+        let (y, m, d) = self.julian();
+        if (0..=9999).contains(&y) {
+            format!("{:04}-{:02}-{:02}", y, m, d)
+        } else {
+            format!("{:+07}-{:02}-{:02}", y, m, d)
+        }
+    }
+    /// Formats the date in ISO 8601 basic format (without separators).
+    ///
+    /// Years outside `0..=9999` are written in the expanded form with a sign
+    /// and 6 digits, e.g. `-0000441231` for December 31, 45 BC (astronomical
+    /// year -44).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!("20000101", date.iso_gregorian_basic());
+    /// ```
+    pub fn iso_gregorian_basic(&self) -> String {
+        let (y, m, d) = self.gregorian();
+        if (0..=9999).contains(&y) {
+            format!("{:04}{:02}{:02}", y, m, d)
+        } else {
+            format!("{:+07}{:02}{:02}", y, m, d)
+        }
+    }
+
+    /// Formats the date (in Gregorian calendar) according to a strftime-like
+    /// `pattern`, using Arabic digits.
+    ///
+    /// See [`format_with`](Self::format_with) for supported specifiers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!("2000-01-01", date.format("%Y-%m-%d"));
+    /// assert_eq!("1/1/2000", date.format("%-m/%-d/%Y"));
+    /// ```
+    pub fn format(&self, pattern: &str) -> String {
+        self.format_with(pattern, Digits::Arabic)
+    }
+    /// Formats the date (in Gregorian calendar) in the common East Asian style
+    /// with Arabic digits and no padding, e.g. `2000年1月1日`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!("2000年1月1日", date.format_cjk());
+    /// ```
+    pub fn format_cjk(&self) -> String {
+        self.format("%-Y年%-m月%-d日")
+    }
+    /// Formats the date (in Gregorian calendar) according to a strftime-like
+    /// `pattern`, with numbers written in `digits`.
+    ///
+    /// Supported specifiers are:
+    ///
+    /// - `%Y`: year, at least 4 digits (with `-` for negative years)
+    /// - `%m`: month, 2 digits
+    /// - `%d`: day of month, 2 digits
+    /// - `%j`: day of year, 3 digits
+    /// - `%u`: day of week, `1..=7` for Monday through Sunday
+    /// - `%%`: a literal `%`
+    ///
+    /// A width between `%` and the specifier (e.g. `%6Y`) overrides the
+    /// zero-padding width, and `-` (e.g. `%-d`) disables padding. Unknown
+    /// specifiers are kept as is.
+    ///
+    /// With [`Digits::Chinese`], padding is ignored and years are written digit
+    /// by digit while other numbers are written as Chinese numerals, see
+    /// [`chinese::fmt::digits`](crate::chinese::fmt::digits) and
+    /// [`chinese::fmt::number`](crate::chinese::fmt::number).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::{Date, Digits};
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!("二〇〇〇年一月一日", date.format_with("%Y年%m月%d日", Digits::Chinese));
+    /// ```
+    pub fn format_with(&self, pattern: &str, digits: Digits) -> String {
+        use crate::chinese::fmt;
+
+        let (y, m, d) = self.gregorian();
+        let mut rt = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                rt.push(c);
+                continue;
+            }
+            let spec = chars.as_str();
+            let flags_len = spec
+                .find(|c: char| !(c == '-' || c.is_ascii_digit()))
+                .unwrap_or(spec.len());
+            let flags = &spec[..flags_len];
+            let spec_char = spec[flags_len..].chars().next();
+            let (value, default_width) = match spec_char {
+                Some('Y') => (y, 4),
+                Some('m') => (m, 2),
+                Some('d') => (d, 2),
+                Some('j') => (ordinal_day_number(m, d, YearType::from_gregorian(y)), 3),
+                Some('u') => (self.day_of_week(), 1),
+                Some('%') if flags.is_empty() => {
+                    rt.push('%');
+                    chars.next();
+                    continue;
+                }
+                _ => {
+                    rt.push('%');
+                    continue;
+                }
+            };
+            chars = spec[flags_len + 1..].chars();
+            match digits {
+                Digits::Chinese if spec_char == Some('Y') => rt += &fmt::digits(value),
+                Digits::Chinese => rt += &fmt::number(value),
+                Digits::Arabic => {
+                    let width = match flags {
+                        "" => default_width,
+                        "-" => 0,
+                        w => w.parse().unwrap_or(default_width),
+                    };
+                    let sign = if value < 0 { "-" } else { "" };
+                    rt += &format!("{}{:0w$}", sign, value.unsigned_abs(), w = width);
+                }
+            }
+        }
+        rt
+    }
+
+    /// Returns the day of week of the date, in ISO-8601 numbering (i.e.
+    /// `1..=7` for Monday through Sunday)
+    ///
+    /// The week cycle is independent of calendars, so this is also the day of
+    /// week of the corresponding Julian calendar date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!(6, date.day_of_week()); // Saturday
+    /// ```
+    pub fn day_of_week(&self) -> i32 {
+        self.weekday().to_iso()
+    }
+    /// Returns the day of week of the date, same as
+    /// [`day_of_week`](Self::day_of_week) but as a [`Weekday`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::{Date, Weekday};
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!(Weekday::Saturday, date.weekday());
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        // JDN 0 is a Monday
+        Weekday::ALL[(self.jdn % 7) as usize]
+    }
+    /// Returns the first date on or after `self` that falls on `weekday` (in
+    /// ISO-8601 numbering, see [`day_of_week`](Self::day_of_week)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap(); // Saturday
+    /// assert_eq!((2000, 1, 3), date.weekday_on_or_after(1).gregorian());
+    /// assert_eq!(date, date.weekday_on_or_after(6));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weekday` is not in `1..=7`.
+    pub fn weekday_on_or_after(&self, weekday: i32) -> Date {
+        assert!(
+            (1..=7).contains(&weekday),
+            "weekday {} not in 1..=7",
+            weekday
+        );
+        *self + (weekday - self.day_of_week()).rem_euclid(7)
+    }
+    /// Returns the last date on or before `self` that falls on `weekday` (in
+    /// ISO-8601 numbering, see [`day_of_week`](Self::day_of_week)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap(); // Saturday
+    /// assert_eq!((1999, 12, 27), date.weekday_on_or_before(1).gregorian());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weekday` is not in `1..=7`.
+    pub fn weekday_on_or_before(&self, weekday: i32) -> Date {
+        *self + -self.days_since_weekday(weekday)
+    }
+    /// Returns the number of days (`0..=6`) since the last `weekday` (in
+    /// ISO-8601 numbering) on or before `self`, i.e. `0` if `self` falls on
+    /// `weekday`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap(); // Saturday
+    /// assert_eq!(5, date.days_since_weekday(1));
+    /// assert_eq!(0, date.days_since_weekday(6));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weekday` is not in `1..=7`.
+    pub fn days_since_weekday(&self, weekday: i32) -> i32 {
+        assert!(
+            (1..=7).contains(&weekday),
+            "weekday {} not in 1..=7",
+            weekday
+        );
+        (self.day_of_week() - weekday).rem_euclid(7)
+    }
+    /// Returns the `n`-th date on or after `self` that falls on `weekday`, e.g.
+    /// for rules like "the second Tuesday on or after the 1st".
+    ///
+    /// `n == 1` is the same as [`weekday_on_or_after`](Self::weekday_on_or_after).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2021, 9, 1).unwrap();
+    /// assert_eq!((2021, 9, 14), date.nth_weekday_on_or_after(2, 2).gregorian());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weekday` is not in `1..=7` or `n` is 0.
+    pub fn nth_weekday_on_or_after(&self, weekday: i32, n: i32) -> Date {
+        assert!(n >= 1, "n must be positive, got {}", n);
+        self.weekday_on_or_after(weekday) + 7 * (n - 1)
+    }
+    /// Returns the Chinese sexagenary day number of the date, numbered from 1
+    /// (甲子) to 60 (癸亥).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!(55, date.sexagenary()); // 戊午
+    /// ```
+    pub fn sexagenary(&self) -> i32 {
+        ((self.jdn % 60 + 49) % 60 + 1) as i32
+    }
+    /// Returns the heavenly stem (天干) of the day, numbered from 1 (甲) to 10
+    /// (癸), i.e. the stem part of [`sexagenary`](Self::sexagenary).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!(5, date.tian_gan_day()); // 戊
+    /// ```
+    pub fn tian_gan_day(&self) -> i32 {
+        ((self.jdn % 10 + 9) % 10 + 1) as i32
+    }
+
+    /// Returns the ISO-8601 week number (with the year of that week) of the
+    /// date, in `(year, week)` format.
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!((1999, 52), date.year_week_gregorian()); // 1999-W52-6
+    /// ```
+    pub fn year_week_gregorian(&self) -> (i32, i32) {
+        let (y, m, d) = self.gregorian();
+        let y_type = YearType::from_gregorian(y);
+        let y_is_leap = y_type.is_leap() as i32;
+        let dn = ordinal_day_number(m, d, y_type);
+        let dow = self.day_of_week();
+        let dow1 = (dow - dn).rem_euclid(7) + 1;
+        if dow1 > 4 && dow1 - 1 + dn <= 7 {
+            use std::cmp::Ordering::*;
+            return match dow1.cmp(&6) {
+                Less => (y - 1, 53),
+                Equal => (y - 1, 52 + YearType::from_gregorian(y - 1).is_leap() as i32),
+                Greater => (y - 1, 52),
+            };
+        }
+        let dow_last = (dow1 + 364 + y_is_leap - 1).rem_euclid(7) + 1;
+        if dow_last < 4 && 365 + y_is_leap + 1 - dn <= dow_last {
+            return (y + 1, 1);
+        }
+
+        (y, (dow1 + dn - 2) / 7 + (dow1 <= 4) as i32)
+    }
+    /// Creates a `Date` with an ISO-8601 week date, the inverse of
+    /// [`year_week_gregorian`](Self::year_week_gregorian) and
+    /// [`day_of_week`](Self::day_of_week).
+    ///
+    /// Returns `None` if `weekday` is not in `1..=7`, if the week does not
+    /// exist in `year` (e.g. week 53 of a year with only 52 weeks), or if the
+    /// result date is out of supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let date = Date::from_iso_week(1999, 52, 6).unwrap();
+    /// assert_eq!((2000, 1, 1), date.gregorian());
+    /// assert_eq!(None, Date::from_iso_week(1999, 53, 1));
+    /// ```
+    pub fn from_iso_week(year: i32, week: i32, weekday: i32) -> Option<Self> {
+        if !(1..=53).contains(&week) || !(1..=7).contains(&weekday) {
+            return None;
+        }
+        // January 4 is always in week 1
+        let jan4 = gregorian_jdn(year.into(), 1, 4);
+        let jdn = jan4 - jan4.rem_euclid(7) + i64::from((week - 1) * 7 + weekday - 1);
+        if !Self::is_jdn_in_range(jdn) {
+            return None;
+        }
+        let date = Self::from_jdn(jdn as u32);
+        Some(date).filter(|date| date.year_week_gregorian() == (year, week))
+    }
+
+    /// Returns the week number (with the year of that week) of the date under
+    /// `scheme`, in `(year, week)` format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::{Date, WeekScheme};
+    ///
+    /// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+    /// assert_eq!((1999, 52), date.year_week(WeekScheme::Iso));
+    /// assert_eq!((2000, 1), date.year_week(WeekScheme::UsSundayStart));
+    /// ```
+    pub fn year_week(&self, scheme: WeekScheme) -> (i32, i32) {
+        match scheme {
+            WeekScheme::Iso => self.year_week_gregorian(),
+            WeekScheme::UsSundayStart => {
+                let (y, m, d) = self.gregorian();
+                let dn = ordinal_day_number(m, d, YearType::from_gregorian(y));
+                // Days from the Sunday on or before January 1
+                let offset = (self.day_of_week() - dn + 1).rem_euclid(7);
+                (y, (dn - 1 + offset) / 7 + 1)
+            }
+        }
+    }
+
+    /// Returns the ordinal day number of the date within its Gregorian century,
+    /// starting from 1.
+    ///
+    /// Centuries are taken to begin with years ending in `00` (e.g. 2000 to
+    /// 2099), rather than the strict ordinal convention where the 21st century
+    /// runs from 2001 to 2100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert_eq!(1, Date::from_gregorian(2000, 1, 1).unwrap().day_of_century());
+    /// assert_eq!(36525, Date::from_gregorian(2099, 12, 31).unwrap().day_of_century());
+    /// ```
+    pub fn day_of_century(&self) -> i32 {
+        self.day_of_period(100)
+    }
+    /// Returns the ordinal day number of the date within its Gregorian decade,
+    /// starting from 1.
+    ///
+    /// Like [`day_of_century`](Self::day_of_century), decades begin with years
+    /// ending in `0` (e.g. 2000 to 2009).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert_eq!(367, Date::from_gregorian(2001, 1, 1).unwrap().day_of_decade());
+    /// ```
+    pub fn day_of_decade(&self) -> i32 {
+        self.day_of_period(10)
+    }
+    fn day_of_period(&self, years: i32) -> i32 {
+        let (y, m, d) = self.gregorian_i64();
+        // The period may start before `Date::MIN`. Shifting by whole 400-year
+        // cycles keeps the day count but makes the years positive.
+        let y = y + 8000;
+        let start = y - y.rem_euclid(years.into());
+        (gregorian_jdn(y, m.into(), d.into()) - gregorian_jdn(start, 1, 1) + 1) as i32
+    }
+
+    /// Returns `true` if the date is February 29 of a Gregorian leap year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// assert!(Date::from_gregorian(2000, 2, 29).unwrap().is_leap_day());
+    /// assert!(!Date::from_gregorian(2000, 2, 28).unwrap().is_leap_day());
+    /// ```
+    pub fn is_leap_day(&self) -> bool {
+        let (y, m, d) = self.gregorian();
+        (m, d) == (2, 29) && YearType::from_gregorian(y).is_leap()
+    }
+
+    /// Returns the calendar difference from `other` to `self` in Gregorian
+    /// calendar, in `(years, months, days)` format, all with the same sign
+    /// (negative if `self` is earlier).
+    ///
+    /// Whole months are counted first, clamping to the end of shorter months
+    /// (e.g. one month after January 31 is February 28 or 29), then the
+    /// remaining days.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::Date;
+    ///
+    /// let a = Date::from_gregorian(2000, 1, 31).unwrap();
+    /// let b = Date::from_gregorian(2001, 3, 1).unwrap();
+    /// assert_eq!((1, 1, 1), b.diff_ymd(a)); // 2001-02-28 plus 1 day
+    /// assert_eq!((-1, -1, -1), a.diff_ymd(b));
+    /// ```
+    pub fn diff_ymd(&self, other: Date) -> (i32, i32, i32) {
+        if *self < other {
+            let (y, m, d) = other.diff_ymd(*self);
+            return (-y, -m, -d);
+        }
+        let (sy, sm, sd) = other.gregorian();
+        let (ey, em, ed) = self.gregorian();
+        let add_months = |months: i32| {
+            let m0 = sm - 1 + months;
+            let (y, m) = (sy + m0.div_euclid(12), m0.rem_euclid(12) + 1);
+            (y, m, sd.min(days_in_month(y, m)))
+        };
+        let mut months = (ey - sy) * 12 + (em - sm);
+        if add_months(months) > (ey, em, ed) {
+            months -= 1;
+        }
+        let (y, m, d) = add_months(months);
+        // Less than a month, but the JDNs may straddle 2^31
+        let days = self.days_since(Date::from_gregorian(y, m, d).unwrap()) as i32;
+        (months / 12, months % 12, days)
+    }
+}
+
+/// Eras for BC/AD style year numbers, see [`Date::from_gregorian_era`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Era {
+    /// Before Common Era (BC), year 1 BCE is astronomical year `0`
+    Bce,
+    /// Common Era (AD)
+    Ce,
+}
+
+/// Day of week, see [`Date::weekday`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All days of week, from Monday through Sunday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+    /// Converts from ISO-8601 numbering, i.e. `1..=7` for Monday through
+    /// Sunday. Returns `None` if `num` is not in `1..=7`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::date::Weekday;
+    ///
+    /// assert_eq!(Some(Weekday::Friday), Weekday::from_iso(5));
+    /// assert_eq!(None, Weekday::from_iso(0));
+    /// ```
+    pub fn from_iso(num: i32) -> Option<Self> {
+        Self::ALL
+            .get(usize::try_from(num).ok()?.checked_sub(1)?)
+            .copied()
+    }
+    /// Converts into ISO-8601 numbering, i.e. `1..=7` for Monday through
+    /// Sunday, as returned by [`Date::day_of_week`].
+    pub fn to_iso(&self) -> i32 {
+        *self as i32 + 1
+    }
+    /// Returns the English name, e.g. `"Monday"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+}
+
+/// Week numbering schemes used by [`Date::year_week`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum WeekScheme {
+    /// ISO-8601: weeks start on Monday, and week 1 is the week containing the
+    /// first Thursday of the year, same as [`Date::year_week_gregorian`]
+    Iso,
+    /// Common US convention: weeks start on Sunday, and week 1 is the week
+    /// containing January 1, so the first and last weeks may be partial
+    UsSundayStart,
+}
+
+/// Digits used by [`Date::format_with`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Digits {
+    /// `0123456789`
+    Arabic,
+    /// `〇一二三四五六七八九`, with `十百千` for numbers other than years
+    Chinese,
+}
+
+/// Parses a date with an optional calendar prefix.
+///
+/// The date part is in `YYYY-MM-DD` format (with an optional leading `-` or
+/// `+` for astronomical year numbers), interpreted according to the prefix:
+///
+/// - `J:` for (proleptic) Julian calendar
+/// - `G:` or no prefix for Gregorian calendar
+///
+/// Returns `None` if the string is malformed, the date does not exist in that
+/// calendar, or is out of supported range.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::date::{parse_any, Date};
+///
+/// let date = Date::from_gregorian(2000, 1, 1).unwrap();
+/// assert_eq!(Some(date), parse_any("2000-01-01"));
+/// assert_eq!(Some(date), parse_any("G:2000-01-01"));
+/// assert_eq!(Some(date), parse_any("J:1999-12-19"));
+/// ```
+pub fn parse_any(s: &str) -> Option<Date> {
+    if let Some(s) = s.strip_prefix("J:") {
+        let (y, m, d) = parse_ymd(s)?;
+        Date::from_julian(y, m, d)
+    } else {
+        let (y, m, d) = parse_ymd(s.strip_prefix("G:").unwrap_or(s))?;
+        Date::try_from((y, m, d)).ok()
+    }
+}
+
+/// Parses `[±]YYYY-MM-DD` into `(year, month, day)` without validation.
+fn parse_ymd(s: &str) -> Option<(i32, i32, i32)> {
+    let (sign, s) = match s.as_bytes().first()? {
+        b'-' => (-1, &s[1..]),
+        b'+' => (1, &s[1..]),
+        _ => (1, s),
+    };
+    let mut it = s.split('-').map(|part| {
+        if !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()) {
+            part.parse::<i32>().ok()
+        } else {
+            None
+        }
+    });
+    let ymd = (sign * it.next()??, it.next()??, it.next()??);
+    match it.next() {
+        None => Some(ymd),
+        Some(_) => None,
+    }
+}
+
+/// A weekly recurrence rule, a simplified form of iCalendar `FREQ=WEEKLY`
+/// rules with weeks starting on Monday, see [`expand_recurrence`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct WeeklyRule {
+    /// Days of week to recur on, in ISO-8601 numbering (`1..=7` for Monday
+    /// through Sunday)
+    pub weekdays: Vec<i32>,
+    /// Number of weeks between recurrences, e.g. `2` for every other week
+    pub interval: i32,
+}
+
+/// Returns the first `count` dates matching `rule`, on or after `start`, in
+/// ascending order.
+///
+/// The week containing `start` is the first recurring week, and every
+/// `rule.interval`-th week after it recurs as well. Returns an empty list if
+/// `rule.weekdays` is empty.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::date::{expand_recurrence, Date, WeeklyRule};
+///
+/// let start = Date::from_gregorian(2024, 1, 1).unwrap(); // Monday
+/// let rule = WeeklyRule { weekdays: vec![1, 4], interval: 2 };
+/// let dates: Vec<_> = expand_recurrence(start, &rule, 4)
+///     .iter()
+///     .map(|date| date.iso_gregorian())
+///     .collect();
+/// assert_eq!(vec!["2024-01-01", "2024-01-04", "2024-01-15", "2024-01-18"], dates);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `rule.interval` is not positive or any of `rule.weekdays` is not
+/// in `1..=7`.
+pub fn expand_recurrence(start: Date, rule: &WeeklyRule, count: usize) -> Vec<Date> {
+    assert!(
+        rule.interval >= 1,
+        "interval must be positive, got {}",
+        rule.interval
+    );
+    for &weekday in &rule.weekdays {
+        assert!(
+            (1..=7).contains(&weekday),
+            "weekday {} not in 1..=7",
+            weekday
+        );
+    }
+    let mut weekdays = rule.weekdays.clone();
+    weekdays.sort_unstable();
+    weekdays.dedup();
+
+    let mut res = Vec::with_capacity(count);
+    if weekdays.is_empty() {
+        return res;
+    }
+    let mut monday = start.weekday_on_or_before(1);
+    loop {
+        for &weekday in &weekdays {
+            let date = monday + (weekday - 1);
+            if res.len() == count {
+                return res;
+            }
+            if date >= start {
+                res.push(date);
+            }
+        }
+        monday = monday + 7 * rule.interval;
+    }
+}
+
+/// Returns the 42 consecutive dates (6 weeks) of a month view in a calendar
+/// grid, for `month` of `year` in Gregorian calendar, starting from the
+/// `week_start` day of week (`1..=7` for Monday through Sunday) on or before
+/// the 1st of the month.
+///
+/// Returns `None` if `month` is not in `1..=12` or the grid is out of
+/// supported range.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::date::{month_grid, Date};
+///
+/// let grid = month_grid(2000, 1, 1).unwrap();
+/// assert_eq!(42, grid.len());
+/// assert_eq!((1999, 12, 27), grid[0].gregorian()); // Monday
+/// assert_eq!((2000, 2, 6), grid[41].gregorian());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `week_start` is not in `1..=7`.
+pub fn month_grid(year: i32, month: i32, week_start: i32) -> Option<Vec<Date>> {
+    assert!(
+        (1..=7).contains(&week_start),
+        "weekday {} not in 1..=7",
+        week_start
+    );
+    let first = Date::try_from((year, month, 1)).ok()?;
+    let start = first
+        .jdn()
+        .checked_sub((first.day_of_week() - week_start).rem_euclid(7) as u32)?;
+    start.checked_add(41)?;
+    Some((0..42).map(|i| Date::from_jdn(start + i)).collect())
+}
+
+/// Converts a Gregorian `(year, month, day)` tuple, in the same format as
+/// returned by [`Date::gregorian`], into a `Date`.
+///
+/// Same as [`Date::from_gregorian`], but returns a `Result`, rejecting
+/// nonexistent dates like February 30 with [`InvalidDate`].
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::Date;
+///
+/// let date = Date::try_from((2000, 1, 1)).unwrap();
+/// assert_eq!(2451545, date.jdn());
+/// assert!(Date::try_from((2021, 2, 30)).is_err());
+/// ```
+impl TryFrom<(i32, i32, i32)> for Date {
+    type Error = InvalidDate;
+    fn try_from((y, m, d): (i32, i32, i32)) -> Result<Self, Self::Error> {
+        Date::from_gregorian(y, m, d).ok_or(InvalidDate)
+    }
+}
+
+/// A Gregorian calendar date in named parts, see [`Date::to_gregorian_parts`].
+///
+/// Converts from and into the `(year, month, day)` tuple as returned by
+/// [`Date::gregorian`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct GregorianDate {
+    /// Astronomical year number, i.e. 1 BC is `0`
+    pub year: i32,
+    /// Month, `1..=12`
+    pub month: i32,
+    /// Day of month, starting from 1
+    pub day: i32,
+}
+
+impl From<(i32, i32, i32)> for GregorianDate {
+    fn from((year, month, day): (i32, i32, i32)) -> Self {
+        Self { year, month, day }
+    }
+}
+impl From<GregorianDate> for (i32, i32, i32) {
+    fn from(date: GregorianDate) -> Self {
+        (date.year, date.month, date.day)
+    }
+}
+/// Same as converting from the tuple form, rejecting nonexistent dates.
+impl TryFrom<GregorianDate> for Date {
+    type Error = InvalidDate;
+    fn try_from(date: GregorianDate) -> Result<Self, Self::Error> {
+        Date::try_from(<(i32, i32, i32)>::from(date))
+    }
+}
+
+/// Commonly used parts of a date, see [`Date::decompose`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DateParts {
+    /// Astronomical year number in Gregorian calendar
+    pub year: i32,
+    /// Month, `1..=12`
+    pub month: i32,
+    /// Day of month, starting from 1
+    pub day: i32,
+    /// Day of year, starting from 1, see [`Date::to_ordinal_parts`]
+    pub ordinal: i32,
+    /// Day of week, see [`Date::day_of_week`]
+    pub weekday: i32,
+}
+
+/// Indicates that a calendar date does not exist or is out of supported range.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct InvalidDate;
+
+impl std::error::Error for InvalidDate {}
+
+impl std::fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid or out-of-range date")
+    }
+}
+
+/// Formats the date in ISO 8601 format, see [`Date::iso_gregorian`].
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::Date;
+///
+/// let date = Date::from_gregorian(10000, 1, 1).unwrap();
+/// assert_eq!("+010000-01-01", format!("{}", date));
+/// ```
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (y, m, d) = self.gregorian();
+        if (0..=9999).contains(&y) {
+            write!(f, "{:04}-{:02}-{:02}", y, m, d)
+        } else {
+            write!(f, "{:+07}-{:02}-{:02}", y, m, d)
+        }
+    }
+}
+
+/// Parses an ISO 8601 date in Gregorian calendar, in `YYYY-MM-DD` format, or
+/// with a sign and at least 4 digits of year for the expanded form, e.g.
+/// `-000044-12-31`, as written by [`Display`](std::fmt::Display).
+///
+/// Unlike [`parse_any`], it takes no calendar prefix, and requires exactly 2
+/// digits of month and day.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::date::{Date, ParseDateError};
+///
+/// let date: Date = "2000-01-01".parse().unwrap();
+/// assert_eq!(2451545, date.jdn());
+/// assert_eq!(Err(ParseDateError::Invalid), "2021-02-30".parse::<Date>());
+/// assert_eq!(Err(ParseDateError::Malformed), "2021-2-3".parse::<Date>());
+/// ```
+impl std::str::FromStr for Date {
+    type Err = ParseDateError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = |b: &[u8]| b.iter().all(u8::is_ascii_digit);
+        let b = s.as_bytes();
+        let shape_ok = b.len() >= 10 && {
+            let (year, md) = b.split_at(b.len() - 6);
+            let year_ok = match year[0] {
+                b'+' | b'-' => year.len() >= 5 && digits(&year[1..]),
+                _ => year.len() == 4 && digits(year),
+            };
+            year_ok && md[0] == b'-' && md[3] == b'-'
+        };
+        let ymd = Some(s).filter(|_| shape_ok).and_then(parse_ymd);
+        let ymd = ymd.ok_or(ParseDateError::Malformed)?;
+        Date::try_from(ymd).map_err(|_| ParseDateError::Invalid)
+    }
+}
+
+/// Serializes as an ISO 8601 string, see [`Date::iso_gregorian`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from an ISO 8601 string, rejecting nonexistent dates as in
+/// [`Date::from_str`](std::str::FromStr::from_str).
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Date;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an ISO 8601 date string")
+            }
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Date, E> {
+                s.parse().map_err(E::custom)
+            }
         }
+        deserializer.deserialize_str(Visitor)
+    }
+}
 
-        (y, (dow1 + dn - 2) / 7 + (dow1 <= 4) as i32)
+/// The error returned when parsing a `Date` from a string fails, see
+/// [`Date::from_str`](std::str::FromStr::from_str).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseDateError {
+    /// The string is not in the expected format
+    Malformed,
+    /// The date does not exist or is out of supported range
+    Invalid,
+}
+
+impl std::error::Error for ParseDateError {}
+
+impl std::fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDateError::Malformed => write!(f, "malformed date, expected YYYY-MM-DD"),
+            ParseDateError::Invalid => write!(f, "invalid or out-of-range date"),
+        }
     }
 }
 
+/// Moves the date forward (or backward if `rhs` is negative) by `rhs` days.
+///
+/// # Panics
+///
+/// Panics in debug mode (and wraps around in release mode) if the result is
+/// out of supported range.
 impl Add<i32> for Date {
     type Output = Date;
     fn add(self, rhs: i32) -> Self::Output {
@@ -156,6 +1582,15 @@ impl Add<i32> for Date {
         })
     }
 }
+/// Returns the number of days from `rhs` to `self`, negative if `self` is
+/// earlier.
+///
+/// # Panics
+///
+/// Panics in debug mode if the result does not fit in an `i32`. The result
+/// wraps around in release mode, and is also wrong if either JDN exceeds
+/// `i32::MAX`. Use [`Date::days_since`] for intervals that may span the full
+/// supported range.
 impl Sub<Date> for Date {
     type Output = i32;
     fn sub(self, rhs: Date) -> Self::Output {
@@ -165,6 +1600,7 @@ impl Sub<Date> for Date {
 
 /// Indicates whether a year is a leap year or common year.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YearType {
     Common,
     Leap,
@@ -179,20 +1615,52 @@ impl YearType {
             Self::Common
         }
     }
+    /// Determines if `year` is a leap year in (proleptic) Julian calendar.
+    pub fn from_julian(year: i32) -> Self {
+        if year % 4 == 0 {
+            Self::Leap
+        } else {
+            Self::Common
+        }
+    }
     /// Returns `true` if `self` is `Leap`, otherwise `false`.
     pub fn is_leap(&self) -> bool {
         matches!(self, YearType::Leap)
     }
 }
 
-fn ordinal_day_number(month: i32, day: i32, year_type: YearType) -> i32 {
-    day + match month {
-        1 => 0,
-        2 => 31,
-        _ => 59 + (153 * (month - 3) + 2) / 5 + year_type.is_leap() as i32,
+/// Number of days before each month, for common and leap years respectively.
+const MONTH_OFFSETS: [[i32; 12]; 2] = [
+    [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
+];
+
+/// JDN of a Gregorian date, possibly out of range.
+fn gregorian_jdn(y: i64, m: i64, d: i64) -> i64 {
+    (1461 * (y + 4800 + (m - 14) / 12)) / 4 + (367 * (m - 2 - 12 * ((m - 14) / 12))) / 12
+        - (3 * ((y + 4900 + (m - 14) / 12) / 100)) / 4
+        + d
+        - 32075
+}
+
+/// Number of days in `month` (`1..=12`) of `year` in Gregorian calendar.
+fn days_in_month(year: i32, month: i32) -> i32 {
+    month_length(month, YearType::from_gregorian(year))
+}
+
+/// `month` must be in `1..=12`.
+fn month_length(month: i32, year_type: YearType) -> i32 {
+    match month {
+        12 => 31,
+        _ => ordinal_day_number(month + 1, 0, year_type) - ordinal_day_number(month, 0, year_type),
     }
 }
 
+/// `month` must be in `1..=12`.
+fn ordinal_day_number(month: i32, day: i32, year_type: YearType) -> i32 {
+    day + MONTH_OFFSETS[year_type.is_leap() as usize][(month - 1) as usize]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,12 +1671,98 @@ mod tests {
         assert_eq!(2440588, date.jdn());
     }
 
+    #[test]
+    fn mutation() {
+        let mut date = Date::from_jdn(2440588);
+        date.advance(10);
+        assert_eq!(2440598, date.jdn());
+        date.advance(-20);
+        assert_eq!(2440578, date.jdn());
+        date.advance(0);
+        assert_eq!(2440578, date.jdn());
+        date.set_jdn(2451545);
+        assert_eq!((2000, 1, 1), date.gregorian());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn advance_overflow() {
+        Date::from_jdn(0).advance(-1);
+    }
+
+    #[test]
+    fn packed() {
+        for jdn in [0, 1, 2440588, 2451545, u32::MAX - 1, u32::MAX] {
+            let date = Date::from_jdn(jdn);
+            assert_eq!(date, Date::from_packed(date.to_packed()));
+        }
+        assert_eq!([0; 4], Date::from_jdn(0).to_packed());
+        assert_eq!([0xff; 4], Date::from_jdn(u32::MAX).to_packed());
+    }
+
     #[test]
     fn from_gregorian() {
         let date = Date::from_gregorian(1970, 1, 1).unwrap();
         assert_eq!(2440588, date.jdn());
         let date = Date::from_gregorian(2021, 9, 8).unwrap();
         assert_eq!(2459466, date.jdn());
+        assert!(Date::from_gregorian(2020, 2, 29).is_some());
+        for (y, m, d) in [
+            (2021, 2, 29),
+            (1900, 2, 29),
+            (2021, 2, 30),
+            (2021, 4, 31),
+            (2021, 1, 0),
+            (2021, 0, 1),
+            (2021, 13, 1),
+            (-4713, 11, 23),
+        ] {
+            assert_eq!(None, Date::from_gregorian(y, m, d), "{:?}", (y, m, d));
+        }
+        assert!(Date::from_gregorian(-4, 2, 29).is_some());
+        assert_eq!(None, Date::from_gregorian(-100, 2, 29));
+        assert_eq!(None, Date::from_gregorian_i64(-100, 2, 29));
+        assert!(Date::from_gregorian_i64(-400, 2, 29).is_some());
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(0, Date::MIN.jdn());
+        assert_eq!(u32::MAX, Date::MAX.jdn());
+        assert_eq!((-4713, 11, 24), Date::MIN.gregorian());
+        assert_eq!((-4712, 1, 1), Date::MIN.julian());
+        assert_eq!((11754508, 12, 13), Date::MAX.gregorian());
+        assert!(Date::is_jdn_in_range(u32::MAX.into()));
+        assert!(!Date::is_jdn_in_range(i64::from(u32::MAX) + 1));
+        assert!(!Date::is_jdn_in_range(-1));
+    }
+
+    #[test]
+    fn gregorian_i64() {
+        let max = Date::from_jdn(u32::MAX);
+        assert_eq!((11754508, 12, 13), max.gregorian_i64());
+        assert_eq!((11754508, 12, 13), max.gregorian());
+        assert_eq!(Some(max), Date::from_gregorian_i64(11754508, 12, 13));
+        assert_eq!(None, Date::from_gregorian_i64(11754508, 12, 14));
+        assert_eq!(
+            None,
+            Date::from_gregorian_i64(i64::from(i32::MAX) + 1, 1, 1)
+        );
+        assert_eq!(None, Date::from_gregorian(i32::MAX, 1, 1));
+        for jdn in [0, 2451545, 1 << 31, u32::MAX - 1] {
+            let (y, m, d) = Date::from_jdn(jdn).gregorian_i64();
+            assert_eq!(Some(Date::from_jdn(jdn)), Date::from_gregorian_i64(y, m, d));
+        }
+    }
+
+    #[test]
+    fn try_from_tuple() {
+        assert_eq!(Ok(Date::from_jdn(2451545)), Date::try_from((2000, 1, 1)));
+        assert_eq!(Err(InvalidDate), Date::try_from((2021, 2, 29)));
+        assert_eq!(Err(InvalidDate), Date::try_from((2021, 13, 1)));
+        assert_eq!(Err(InvalidDate), Date::try_from((2021, 1, 0)));
+        assert_eq!(Err(InvalidDate), Date::try_from((-5000, 1, 1)));
     }
 
     #[test]
@@ -221,6 +1775,93 @@ mod tests {
         assert_eq!((2000, 1, 1), date.gregorian());
     }
 
+    #[test]
+    fn julian() {
+        for ((y, m, d), jdn) in [
+            ((-4712, 1, 1), 0),
+            ((1582, 10, 4), 2299160),
+            ((1900, 2, 29), 2415092),
+            ((1999, 12, 19), 2451545),
+            ((5874777, 10, 18), 1 << 31),
+            ((11754267, 8, 4), u32::MAX),
+        ] {
+            assert_eq!(Some(Date::from_jdn(jdn)), Date::from_julian(y, m, d));
+            assert_eq!((y, m, d), Date::from_jdn(jdn).julian());
+        }
+        assert_eq!(None, Date::from_julian(-4713, 12, 31));
+        assert_eq!(None, Date::from_julian(11754267, 8, 5));
+        for (y, m, d) in [
+            (2021, 2, 29),
+            (2021, 2, 30),
+            (2021, 14, 1),
+            (2021, 0, 1),
+            (2021, 4, 31),
+            (2021, 1, 0),
+        ] {
+            assert_eq!(None, Date::from_julian(y, m, d), "{:?}", (y, m, d));
+        }
+        assert_eq!(None, Date::from_julian(i32::MIN, i32::MIN, 1));
+        assert_eq!(None, Date::from_julian(i32::MAX, 12, 31));
+        assert!(Date::from_julian(-4, 2, 29).is_some());
+        assert_eq!(None, Date::from_julian(-1, 2, 29));
+        assert_eq!((5874777, 10, 17), (Date::from_jdn(1 << 31) + -1).julian());
+    }
+
+    #[test]
+    fn julian_ordinal() {
+        for ((y, m, d), std) in [
+            ((1900, 2, 29), 60),
+            ((1900, 3, 1), 61),
+            ((1901, 3, 1), 60),
+            ((1900, 12, 31), 366),
+            ((-1, 12, 31), 365),
+        ] {
+            let date = Date::from_julian(y, m, d).unwrap();
+            assert_eq!(std, date.ordinal_julian(), "{:?}", (y, m, d));
+        }
+        assert_eq!(216, Date::MAX.ordinal_julian()); // August 4
+        assert_eq!(291, Date::from_jdn(1 << 31).ordinal_julian()); // October 18
+        assert_eq!(1, Date::MIN.ordinal_julian());
+        assert!(YearType::from_julian(-4).is_leap());
+        assert!(!YearType::from_julian(-1).is_leap());
+        assert!(YearType::from_julian(1900).is_leap());
+        assert!(!YearType::from_gregorian(1900).is_leap());
+    }
+
+    #[test]
+    fn parse_with_prefix() {
+        let date = Date::from_gregorian(1582, 10, 15).unwrap();
+        assert_eq!(Some(date), parse_any("1582-10-15"));
+        assert_eq!(Some(date), parse_any("G:1582-10-15"));
+        assert_eq!(Some(date), parse_any("J:1582-10-05"));
+        assert_eq!(Some(date + -1), parse_any("J:1582-10-04"));
+        assert_eq!(Some(Date::from_jdn(0)), parse_any("J:-4712-01-01"));
+        assert_eq!(Some(Date::from_jdn(0)), parse_any("G:-4713-11-24"));
+        assert_eq!(
+            Some(Date::from_julian(1900, 2, 29).unwrap()),
+            parse_any("J:1900-02-29")
+        );
+        let date = parse_any("J:6000000-01-01").unwrap();
+        assert!(date.jdn() > 1 << 31);
+        assert_eq!((6000000, 1, 1), date.julian());
+        assert_eq!(Some(Date::MAX), parse_any("J:11754267-08-04"));
+        assert_eq!(None, parse_any("J:11754267-08-05"));
+        for s in [
+            "G:1900-02-29",
+            "1900-02-29",
+            "J:1900-02-30",
+            "J:-4713-12-31",
+            "X:2000-01-01",
+            "2000-01",
+            "2000-01-01-01",
+            "2000-1a-01",
+            "2000--01",
+            "",
+        ] {
+            assert_eq!(None, parse_any(s), "{}", s);
+        }
+    }
+
     #[test]
     fn to_day_of_week() {
         let date = Date::from_gregorian(1970, 1, 1).unwrap();
@@ -229,6 +1870,36 @@ mod tests {
         assert_eq!(3, date.day_of_week());
     }
 
+    #[test]
+    fn weekday_navigation() {
+        let date = Date::from_gregorian(2021, 9, 8).unwrap(); // Wednesday
+        for (weekday, after, before) in [
+            (1, (2021, 9, 13), (2021, 9, 6)),
+            (3, (2021, 9, 8), (2021, 9, 8)),
+            (4, (2021, 9, 9), (2021, 9, 2)),
+            (7, (2021, 9, 12), (2021, 9, 5)),
+        ] {
+            assert_eq!(after, date.weekday_on_or_after(weekday).gregorian());
+            assert_eq!(before, date.weekday_on_or_before(weekday).gregorian());
+            assert_eq!(
+                date.weekday_on_or_after(weekday),
+                date.nth_weekday_on_or_after(weekday, 1)
+            );
+            assert_eq!(
+                date.weekday_on_or_after(weekday) + 7,
+                date.nth_weekday_on_or_after(weekday, 2)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn weekday_out_of_range() {
+        Date::from_gregorian(2021, 9, 8)
+            .unwrap()
+            .weekday_on_or_after(0);
+    }
+
     #[test]
     fn to_sexagenary() {
         let date = Date::from_gregorian(1970, 1, 1).unwrap();
@@ -261,6 +1932,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn day_of_period() {
+        for ((y, m, d), century, decade) in [
+            ((1900, 1, 1), 1, 1),
+            ((1999, 12, 31), 36524, 3652),
+            ((2000, 1, 1), 1, 1),
+            ((2000, 12, 31), 366, 366),
+            ((2010, 1, 1), 3654, 1),
+            ((2099, 12, 31), 36525, 3652),
+            ((-1, 1, 1), 36160, 3288),
+            // Periods starting before `Date::MIN`
+            ((-4713, 11, 24), 32105, 2885),
+            ((-4712, 6, 1), 32295, 3075),
+            ((11754508, 12, 13), 3269, 3269),
+        ] {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(century, date.day_of_century(), "{:?}", (y, m, d));
+            assert_eq!(decade, date.day_of_decade(), "{:?}", (y, m, d));
+        }
+        assert_eq!(Date::from_gregorian(-4713, 11, 24), Some(Date::MIN));
+        // Consistent with counting from the start of the period where it is in range
+        for date in [Date::MIN + 36524, Date::MIN + 40000, Date::MAX] {
+            let y = date.gregorian().0;
+            let start = Date::from_gregorian(y - y.rem_euclid(100), 1, 1).unwrap();
+            assert_eq!(date.days_since(start) + 1, i64::from(date.day_of_century()));
+        }
+    }
+
+    #[test]
+    fn leap_day() {
+        assert!(Date::from_gregorian(2000, 2, 29).unwrap().is_leap_day());
+        assert!(Date::from_gregorian(2024, 2, 29).unwrap().is_leap_day());
+        assert!(!Date::from_gregorian(2000, 2, 28).unwrap().is_leap_day());
+        assert!(!Date::from_gregorian(2000, 3, 1).unwrap().is_leap_day());
+        assert_eq!(None, Date::from_gregorian(1900, 2, 29));
+    }
+
+    #[test]
+    fn format_pattern() {
+        let date = Date::from_gregorian(2000, 1, 1).unwrap();
+        for (pattern, std) in [
+            ("%Y-%m-%d", "2000-01-01"),
+            ("%-m/%-d/%Y", "1/1/2000"),
+            ("%6Y%3m", "002000001"),
+            ("%Y-%j (%u)", "2000-001 (6)"),
+            ("100%% %q %", "100% %q %"),
+        ] {
+            assert_eq!(std, date.format(pattern), "{}", pattern);
+        }
+        let date = Date::from_gregorian(-44, 3, 15).unwrap();
+        assert_eq!("-0044-03-15", date.format("%Y-%m-%d"));
+
+        use crate::chinese::fmt;
+        let date = Date::from_gregorian(2017, 12, 25).unwrap();
+        assert_eq!(
+            "二〇一七年十二月二十五日",
+            date.format_with("%Y年%m月%d日", Digits::Chinese)
+        );
+        assert_eq!(
+            format!("{}年{}月", fmt::digits(2017), fmt::number(12)),
+            date.format_with("%4Y年%-m月", Digits::Chinese)
+        );
+        assert_eq!("三百五十九", date.format_with("%j", Digits::Chinese));
+    }
+
+    #[test]
+    fn iso_basic_format() {
+        for ((y, m, d), std) in [
+            ((2000, 1, 1), "20000101"),
+            ((0, 3, 1), "00000301"),
+            ((9999, 12, 31), "99991231"),
+            ((-44, 12, 31), "-0000441231"),
+            ((-4713, 11, 24), "-0047131124"),
+            ((10000, 1, 1), "+0100000101"),
+        ] {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(std, date.iso_gregorian_basic());
+        }
+    }
+
     #[test]
     fn iso_format() {
         assert_eq!(
@@ -268,6 +2019,545 @@ mod tests {
             Date::from_gregorian(2021, 9, 8).unwrap().iso_gregorian()
         );
     }
+
+    #[test]
+    fn display_from_str() {
+        for ((y, m, d), std) in [
+            ((2000, 1, 1), "2000-01-01"),
+            ((0, 3, 1), "0000-03-01"),
+            ((9999, 12, 31), "9999-12-31"),
+            ((-44, 12, 31), "-000044-12-31"),
+            ((-4713, 11, 24), "-004713-11-24"),
+            ((10000, 1, 1), "+010000-01-01"),
+            ((11754508, 12, 13), "+11754508-12-13"),
+        ] {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(std, date.to_string());
+            assert_eq!(std, date.iso_gregorian());
+            assert_eq!(Ok(date), std.parse());
+        }
+        let date = Date::from_gregorian(2000, 1, 1).unwrap();
+        assert_eq!(Ok(date), "+2000-01-01".parse());
+        assert_eq!(Ok(date), "+002000-01-01".parse());
+        for s in [
+            "2021-02-30",
+            "2021-02-29",
+            "2021-13-01",
+            "-004713-11-23",
+            "+11754508-12-14",
+        ] {
+            assert_eq!(Err(ParseDateError::Invalid), s.parse::<Date>(), "{}", s);
+        }
+        for s in [
+            "",
+            "2000",
+            "2000-01",
+            "200-01-01",
+            "02000-01-01",
+            "2000-1-01",
+            "2000-01-1",
+            "2000-001-1",
+            "2000/01/01",
+            "-200-01-01",
+            "+-2000-01-01",
+            "2000-01-01-01",
+            "G:2000-01-01",
+            " 2000-01-01",
+            "２０００-01-01",
+            "2000-0１-01",
+        ] {
+            assert_eq!(Err(ParseDateError::Malformed), s.parse::<Date>(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn signed_diffs() {
+        let a = Date::from_gregorian(2000, 1, 1).unwrap();
+        assert_eq!(Some(0), a.checked_signed_diff(a));
+        assert_eq!(
+            Some(i32::MAX),
+            (Date::MIN + i32::MAX).checked_signed_diff(Date::MIN)
+        );
+        assert_eq!(
+            None,
+            (Date::MIN + i32::MAX + 1).checked_signed_diff(Date::MIN)
+        );
+        assert_eq!(
+            Some(i32::MIN),
+            Date::MIN.checked_signed_diff(Date::MIN + i32::MAX + 1)
+        );
+        let span = Date::MAX.signed_diff_i64(Date::MIN);
+        assert!(span > i32::MAX as i64);
+        assert_eq!(-span, Date::MIN.signed_diff_i64(Date::MAX));
+        assert_eq!(
+            60,
+            Date::from_gregorian(2000, 3, 1).unwrap().signed_diff_i64(a)
+        );
+        for (x, y) in [(Date::MIN, Date::MAX), (a, a + 1), (a, Date::MAX), (a, a)] {
+            assert_eq!(x.signed_diff_i64(y), x.days_since(y));
+            assert_eq!(-x.days_since(y), y.days_since(x));
+        }
+        assert_eq!(-1, a.days_since(a + 1));
+        assert_eq!(i64::from(u32::MAX), Date::MAX.days_since(Date::MIN));
+    }
+
+    #[test]
+    fn tian_gan() {
+        for jdn in [0, 1, 9, 10, 2451545, 2457754, u32::MAX - 5, u32::MAX] {
+            let date = Date::from_jdn(jdn);
+            assert_eq!((date.sexagenary() - 1) % 10 + 1, date.tian_gan_day());
+        }
+        assert_eq!(1, Date::from_gregorian(2000, 1, 7).unwrap().tian_gan_day()); // 甲子
+        assert_eq!(
+            10,
+            Date::from_gregorian(2000, 1, 16).unwrap().tian_gan_day()
+        ); // 癸酉
+    }
+
+    #[test]
+    fn cjk_format() {
+        for (std, (y, m, d)) in [
+            ("2000年1月1日", (2000, 1, 1)),
+            ("2017年12月25日", (2017, 12, 25)),
+            ("800年10月9日", (800, 10, 9)),
+            ("-44年3月15日", (-44, 3, 15)),
+        ] {
+            assert_eq!(std, Date::from_gregorian(y, m, d).unwrap().format_cjk());
+        }
+    }
+
+    #[test]
+    fn historical() {
+        let julian = Date::from_historical(1582, 10, 4).unwrap();
+        let gregorian = Date::from_historical(1582, 10, 15).unwrap();
+        assert_eq!(julian.jdn() + 1, gregorian.jdn());
+        assert_eq!(Date::from_julian(1582, 10, 4), Some(julian));
+        assert_eq!(Date::from_gregorian(1582, 10, 15), Some(gregorian));
+        for d in 5..=14 {
+            assert_eq!(None, Date::from_historical(1582, 10, d));
+        }
+        // 1500 is a leap year in Julian calendar only
+        assert!(Date::from_historical(1500, 2, 29).is_some());
+        assert_eq!(None, Date::from_historical(1700, 2, 29));
+        assert_eq!(None, Date::from_historical(2000, 13, 1));
+        assert_eq!(None, Date::from_historical(-4713, 1, 0));
+    }
+
+    #[test]
+    fn ymd_diff() {
+        let g = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        let dataset = [
+            ((2000, 1, 31), (2001, 3, 1), (1, 1, 1)),
+            ((2000, 1, 1), (2000, 1, 1), (0, 0, 0)),
+            ((2000, 1, 31), (2000, 2, 29), (0, 1, 0)),
+            ((2000, 1, 31), (2000, 2, 28), (0, 0, 28)),
+            ((2000, 1, 31), (2000, 3, 31), (0, 2, 0)),
+            ((2000, 2, 29), (2001, 2, 28), (1, 0, 0)),
+            ((2000, 2, 29), (2004, 2, 29), (4, 0, 0)),
+            ((1999, 12, 15), (2000, 1, 14), (0, 0, 30)),
+            ((1999, 12, 15), (2000, 1, 15), (0, 1, 0)),
+            ((-1, 6, 1), (1, 5, 31), (1, 11, 30)),
+        ];
+        for (from, to, std) in dataset {
+            let (from, to) = (g(from.0, from.1, from.2), g(to.0, to.1, to.2));
+            assert_eq!(std, to.diff_ymd(from), "{:?} to {:?}", from, to);
+            assert_eq!((-std.0, -std.1, -std.2), from.diff_ymd(to));
+        }
+        let (from, to) = (Date::from_jdn((1 << 31) - 3), Date::from_jdn((1 << 31) + 5));
+        assert_eq!((0, 0, 8), to.diff_ymd(from));
+        assert_eq!((0, 0, -8), from.diff_ymd(to));
+    }
+
+    #[test]
+    fn weekly_recurrence() {
+        let g = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        let rule = WeeklyRule {
+            weekdays: vec![4, 1],
+            interval: 2,
+        };
+        let std = [
+            g(2024, 1, 4),
+            g(2024, 1, 15),
+            g(2024, 1, 18),
+            g(2024, 1, 29),
+            g(2024, 2, 1),
+            g(2024, 2, 12),
+        ];
+        assert_eq!(std.to_vec(), expand_recurrence(g(2024, 1, 3), &rule, 6));
+        assert_eq!(
+            std[..3].to_vec(),
+            expand_recurrence(g(2024, 1, 4), &rule, 3)
+        );
+        assert!(expand_recurrence(g(2024, 1, 4), &rule, 0).is_empty());
+
+        let weekly = WeeklyRule {
+            weekdays: vec![7, 7],
+            interval: 1,
+        };
+        let dates = expand_recurrence(g(2024, 1, 1), &weekly, 3);
+        assert_eq!(vec![g(2024, 1, 7), g(2024, 1, 14), g(2024, 1, 21)], dates);
+
+        let none = WeeklyRule {
+            weekdays: vec![],
+            interval: 1,
+        };
+        assert!(expand_recurrence(g(2024, 1, 1), &none, 3).is_empty());
+    }
+
+    #[test]
+    fn lenient_gregorian() {
+        let dataset = [
+            ((2000, 1, 32), (2000, 2, 1)),
+            ((2000, 2, 30), (2000, 3, 1)),
+            ((2001, 2, 29), (2001, 3, 1)),
+            ((2000, 1, 0), (1999, 12, 31)),
+            ((2000, 13, 1), (2001, 1, 1)),
+            ((2000, 0, 1), (1999, 12, 1)),
+            ((2000, -11, 1), (1999, 1, 1)),
+            ((2000, 1, 366), (2000, 12, 31)),
+            ((2000, 12, -30), (2000, 10, 31)),
+            ((2000, 6, 15), (2000, 6, 15)),
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian_lenient(y, m, d).unwrap();
+            assert_eq!(std, date.gregorian(), "{:?}", (y, m, d));
+            assert_eq!(std != (y, m, d), Date::try_from((y, m, d)).is_err());
+        }
+        assert_eq!(Some(Date::MIN), Date::from_gregorian_lenient(-4713, 11, 24));
+        assert_eq!(None, Date::from_gregorian_lenient(-4713, 11, 23));
+        assert_eq!(
+            None,
+            Date::from_gregorian_lenient(i32::MAX, i32::MAX, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn normalized_gregorian() {
+        let dataset = [
+            ((2000, 0, 1), (1999, 12, 1)),
+            ((2000, 13, 1), (2001, 1, 1)),
+            ((2000, -12, 31), (1998, 12, 31)),
+            ((2000, 14, 28), (2001, 2, 28)),
+            ((2000, 26, 29), (2002, 2, 29)),
+            ((2000, 6, 15), (2000, 6, 15)),
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian_normalized(y, m, d);
+            let std = Some(std).filter(|&std| Date::try_from(std).is_ok());
+            assert_eq!(std, date.map(|date| date.gregorian()), "{:?}", (y, m, d));
+        }
+        for (y, m, d) in [(2000, 14, 29), (2000, 0, 32), (2000, 1, 0)] {
+            assert_eq!(None, Date::from_gregorian_normalized(y, m, d));
+        }
+        assert_eq!(
+            Some(Date::from_gregorian(2024, 2, 29).unwrap()),
+            Date::from_gregorian_normalized(2023, 14, 29)
+        );
+        assert_eq!(None, Date::from_gregorian_normalized(i32::MAX, i32::MAX, 1));
+        assert_eq!(None, Date::from_gregorian_normalized(i32::MIN, i32::MIN, 1));
+    }
+
+    #[test]
+    fn gregorian_parts() {
+        for jdn in [0, 1721426, 2299161, 2451545, 2460000, u32::MAX] {
+            let date = Date::from_jdn(jdn);
+            let (y, m, d) = date.gregorian();
+            let parts = date.to_gregorian_parts();
+            assert_eq!((y, m, d), (parts.year, parts.month, parts.day));
+            assert_eq!(parts, GregorianDate::from((y, m, d)));
+            assert_eq!(Ok(date), Date::try_from(parts));
+        }
+        let invalid = GregorianDate {
+            year: 2021,
+            month: 2,
+            day: 29,
+        };
+        assert_eq!(Err(InvalidDate), Date::try_from(invalid));
+    }
+
+    #[test]
+    fn week_schemes() {
+        use WeekScheme::*;
+        let dataset = [
+            ((2000, 1, 1), (1999, 52), (2000, 1)),
+            ((2000, 1, 2), (1999, 52), (2000, 2)),
+            ((2000, 1, 3), (2000, 1), (2000, 2)),
+            ((2000, 12, 31), (2000, 52), (2000, 54)),
+            ((2017, 1, 1), (2016, 52), (2017, 1)),
+            ((2017, 1, 7), (2017, 1), (2017, 1)),
+            ((2017, 1, 8), (2017, 1), (2017, 2)),
+            ((2017, 12, 31), (2017, 52), (2017, 53)),
+        ];
+        for ((y, m, d), iso, us) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(iso, date.year_week(Iso), "{:?}", (y, m, d));
+            assert_eq!(us, date.year_week(UsSundayStart), "{:?}", (y, m, d));
+        }
+    }
+
+    #[test]
+    fn month_grids() {
+        for (y, m) in [(2000, 1), (2000, 2), (2015, 2), (2021, 8), (2024, 12)] {
+            let first = Date::from_gregorian(y, m, 1).unwrap();
+            let next = Date::from_gregorian_lenient(y, m + 1, 1).unwrap();
+            for week_start in 1..=7 {
+                let grid = month_grid(y, m, week_start).unwrap();
+                assert_eq!(42, grid.len());
+                assert_eq!(week_start, grid[0].day_of_week());
+                assert!(grid[0] <= first && first - grid[0] < 7);
+                assert!(grid.windows(2).all(|w| w[1] - w[0] == 1));
+                assert!(grid.contains(&first) && grid.contains(&(next + -1)));
+            }
+        }
+        // February 2015 starts on Sunday and has exactly 4 weeks
+        assert_eq!((2015, 2, 1), month_grid(2015, 2, 7).unwrap()[0].gregorian());
+        assert_eq!(None, month_grid(2000, 13, 1));
+        assert_eq!(None, month_grid(-4713, 11, 1));
+    }
+
+    #[test]
+    fn iso_julian_format() {
+        let date = Date::from_gregorian(2024, 3, 1).unwrap();
+        assert_eq!("2024-03-01", date.iso_gregorian());
+        assert_eq!("2024-02-17", date.iso_julian());
+        assert_eq!(Date::from_julian(2024, 2, 17), Some(date));
+        // Julian and Gregorian dates are 13 days apart in the 21st century
+        for (y, m, d) in [(2001, 1, 1), (2050, 7, 15), (2100, 2, 28)] {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(date.julian(), (date + -13).gregorian());
+        }
+        let date = Date::from_historical(1582, 10, 4).unwrap();
+        assert_eq!("1582-10-04", date.iso_julian());
+        assert_eq!("1582-10-14", date.iso_gregorian());
+        assert_eq!("+11754267-08-04", Date::MAX.iso_julian());
+        assert_eq!("+5874777-10-18", Date::from_jdn(1 << 31).iso_julian());
+        assert_eq!("-004712-01-01", Date::MIN.iso_julian());
+        assert_eq!(
+            "-000001-03-03",
+            Date::from_julian(-1, 3, 3).unwrap().iso_julian()
+        );
+        assert_eq!(4, date.day_of_week()); // Thursday
+        assert_eq!(5, (date + 1).day_of_week()); // Friday, October 15
+    }
+
+    #[test]
+    fn eras() {
+        use Era::*;
+        let dataset = [
+            ((Bce, 1, 1, 1), 0),
+            ((Bce, 2, 12, 31), -1),
+            ((Bce, 4713, 11, 24), -4712),
+            ((Ce, 1, 1, 1), 1),
+            ((Ce, 2000, 1, 1), 2000),
+        ];
+        for ((era, y, m, d), std) in dataset {
+            let date = Date::from_gregorian_era(era, y, m, d).unwrap();
+            assert_eq!((std, m, d), date.gregorian());
+            assert_eq!((era, y, m, d), date.gregorian_era());
+        }
+        assert_eq!(
+            1,
+            Date::from_gregorian_era(Ce, 1, 1, 1).unwrap()
+                - Date::from_gregorian_era(Bce, 1, 12, 31).unwrap()
+        );
+        assert_eq!(None, Date::from_gregorian_era(Ce, 0, 1, 1));
+        assert_eq!(None, Date::from_gregorian_era(Bce, -1, 1, 1));
+        assert_eq!(None, Date::from_gregorian_era(Bce, 4714, 1, 1));
+    }
+
+    #[test]
+    fn days_since_weekdays() {
+        let wednesday = Date::from_gregorian(2000, 1, 5).unwrap();
+        assert_eq!(3, wednesday.day_of_week());
+        for (weekday, std) in (1..=7).zip([2, 1, 0, 6, 5, 4, 3]) {
+            assert_eq!(std, wednesday.days_since_weekday(weekday));
+            assert_eq!(weekday, (wednesday + -std).day_of_week());
+        }
+    }
+
+    #[test]
+    fn ordinal_parts() {
+        let dataset = [
+            ((2000, 1, 1), (2000, 1)),
+            ((2000, 3, 1), (2000, 61)),
+            ((2000, 12, 31), (2000, 366)),
+            ((2001, 12, 31), (2001, 365)),
+            ((-44, 3, 15), (-44, 75)),
+        ];
+        for ((y, m, d), std) in dataset {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(std, date.to_ordinal_parts());
+            assert_eq!(Some(date), Date::from_ordinal_parts(std));
+        }
+        for parts in [(2000, 0), (2000, 367), (2001, 366), (-4714, 1)] {
+            assert_eq!(None, Date::from_ordinal_parts(parts), "{:?}", parts);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "clock")]
+    fn today() {
+        let today = Date::today(480);
+        assert!(today.gregorian().0 >= 2021);
+        assert!((Date::today(-720) - today).abs() <= 1);
+        let days = (Date::today(0) + 10).days_from_today(0);
+        assert!(days == 10 || days == 9);
+        assert!(Date::MAX.days_from_today(0) > i64::from(i32::MAX));
+        assert!(Date::MIN.days_from_today(0) < -2400000);
+    }
+
+    #[test]
+    fn duration_since() {
+        use std::time::Duration;
+        let date = Date::from_gregorian(2023, 12, 25).unwrap();
+        assert_eq!(
+            Some(Duration::from_secs(864000)),
+            (date + 10).duration_since(date)
+        );
+        assert_eq!(None, date.duration_since(date + 10));
+        assert_eq!(Some(Duration::ZERO), date.duration_since(date));
+        assert_eq!(
+            Some(Duration::from_secs(u64::from(u32::MAX) * 86400)),
+            Date::MAX.duration_since(Date::MIN)
+        );
+    }
+
+    #[test]
+    fn decompose() {
+        for jdn in (0..u32::MAX - 1_000_000)
+            .step_by(999_983)
+            .chain(u32::MAX - 1000..=u32::MAX)
+        {
+            let date = Date::from_jdn(jdn);
+            let parts = date.decompose();
+            let (year, month, day) = date.gregorian();
+            assert_eq!((year, month, day), (parts.year, parts.month, parts.day));
+            assert_eq!((year, parts.ordinal), date.to_ordinal_parts());
+            assert_eq!(date.day_of_week(), parts.weekday);
+        }
+    }
+
+    #[test]
+    fn gregorian_ordinal() {
+        for (y, m, d, ord) in [
+            (2000, 1, 1, 1),
+            (2000, 12, 31, 366),
+            (1900, 12, 31, 365),
+            (2023, 3, 1, 60),
+            (2024, 3, 1, 61),
+        ] {
+            let date = Date::from_gregorian(y, m, d).unwrap();
+            assert_eq!(ord, date.ordinal_gregorian());
+            assert_eq!(Some(date), Date::from_gregorian_ordinal(y, ord));
+        }
+        for (y, ord) in [(1900, 366), (2023, 366), (2000, 367), (2000, 0), (2000, -1)] {
+            assert_eq!(None, Date::from_gregorian_ordinal(y, ord));
+        }
+        for jdn in (0..u32::MAX).step_by(9_999_991).chain([u32::MAX]) {
+            let date = Date::from_jdn(jdn);
+            let year = date.gregorian().0;
+            let ord = date.ordinal_gregorian();
+            assert_eq!(Some(date), Date::from_gregorian_ordinal(year, ord));
+        }
+    }
+
+    #[test]
+    fn iso_week_date() {
+        let dataset = [
+            ((1981, 53, 4), (1981, 12, 31)),
+            ((1981, 53, 7), (1982, 1, 3)),
+            ((1982, 1, 1), (1982, 1, 4)),
+            ((2020, 53, 5), (2021, 1, 1)),
+            ((2020, 53, 7), (2021, 1, 3)),
+            ((2020, 1, 1), (2019, 12, 30)),
+            ((2021, 1, 1), (2021, 1, 4)),
+        ];
+        for ((y, w, wd), std) in dataset {
+            let date = Date::from_iso_week(y, w, wd).unwrap();
+            assert_eq!(std, date.gregorian(), "{:?}", (y, w, wd));
+            assert_eq!((y, w), date.year_week_gregorian());
+            assert_eq!(wd, date.day_of_week());
+        }
+        for (y, w, wd) in [
+            (2021, 53, 1),
+            (2019, 53, 1),
+            (2020, 0, 1),
+            (2020, 54, 1),
+            (2020, 1, 0),
+            (2020, 1, 8),
+        ] {
+            assert_eq!(None, Date::from_iso_week(y, w, wd), "{:?}", (y, w, wd));
+        }
+        for jdn in (10..u32::MAX - 10).step_by(999_983) {
+            let date = Date::from_jdn(jdn);
+            let (y, w) = date.year_week_gregorian();
+            assert_eq!(Some(date), Date::from_iso_week(y, w, date.day_of_week()));
+        }
+        assert_eq!(None, Date::from_iso_week(-4713, 1, 1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let date = Date::from_gregorian(2000, 1, 1).unwrap();
+        assert_eq!("\"2000-01-01\"", serde_json::to_string(&date).unwrap());
+        assert_eq!(
+            date,
+            serde_json::from_str::<Date>("\"2000-01-01\"").unwrap()
+        );
+        let date = Date::from_gregorian(-44, 3, 15).unwrap();
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(date, serde_json::from_str::<Date>(&json).unwrap());
+        for json in ["\"2021-02-30\"", "\"2021-2-3\"", "2451545"] {
+            assert!(serde_json::from_str::<Date>(json).is_err(), "{}", json);
+        }
+        let json = serde_json::to_string(&YearType::Leap).unwrap();
+        assert_eq!(YearType::Leap, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn weekday_enum() {
+        for (i, &weekday) in Weekday::ALL.iter().enumerate() {
+            let num = i as i32 + 1;
+            assert_eq!(num, weekday.to_iso());
+            assert_eq!(Some(weekday), Weekday::from_iso(num));
+        }
+        for num in [0, 8, -1, i32::MIN, i32::MAX] {
+            assert_eq!(None, Weekday::from_iso(num));
+        }
+        assert_eq!("Friday", Weekday::Friday.name());
+        let date = Date::from_gregorian(2024, 1, 1).unwrap();
+        for i in 0..14 {
+            let date = date + i;
+            assert_eq!(Weekday::ALL[i as usize % 7], date.weekday());
+            assert_eq!(date.day_of_week(), date.weekday().to_iso());
+        }
+        assert_eq!(Weekday::Monday, Date::MIN.weekday());
+    }
+    #[test]
+    fn unix_days() {
+        let g = |y, m, d| Date::from_gregorian(y, m, d).unwrap();
+        assert_eq!(Some(g(1970, 1, 1)), Date::from_unix_timestamp(0));
+        assert_eq!(Some(g(1970, 1, 2)), Date::from_unix_timestamp(86400));
+        assert_eq!(Some(g(1970, 1, 1)), Date::from_unix_timestamp(86399));
+        assert_eq!(Some(g(1969, 12, 31)), Date::from_unix_timestamp(-86400));
+        assert_eq!(Some(g(1969, 12, 30)), Date::from_unix_timestamp(-86401));
+        assert_eq!(Some(g(2000, 1, 1)), Date::from_unix_timestamp(946684800));
+        assert_eq!(None, Date::from_unix_timestamp(i64::MIN));
+        assert_eq!(None, Date::from_unix_timestamp(i64::MAX));
+
+        assert_eq!(-2440588, Date::MIN.unix_days());
+        assert_eq!(Some(Date::MIN), Date::from_unix_days(-2440588));
+        assert_eq!(None, Date::from_unix_days(-2440589));
+        assert_eq!(Some(Date::MAX), Date::from_unix_days(Date::MAX.unix_days()));
+        assert_eq!(None, Date::from_unix_days(Date::MAX.unix_days() + 1));
+        assert_eq!(None, Date::from_unix_days(i64::MAX));
+        for days in (-100000..100000).step_by(997) {
+            let date = Date::from_unix_days(days).unwrap();
+            assert_eq!(days, date.unix_days());
+            assert_eq!(Some(date), Date::from_unix_timestamp(days * 86400 + 43200));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -280,5 +2570,45 @@ mod tests_priv {
         assert_eq!(1, ordinal_day_number(1, 1, Common));
         assert_eq!(256, ordinal_day_number(9, 13, Common));
         assert_eq!(366, ordinal_day_number(12, 31, Leap));
+        assert_eq!(29, days_in_month(2000, 2));
+        assert_eq!(28, days_in_month(1900, 2));
+        assert_eq!(31, days_in_month(2000, 12));
+    }
+
+    #[test]
+    #[cfg(feature = "clock")]
+    fn priv_from_unix_secs() {
+        let dataset = [
+            (0, 0, (1970, 1, 1)),
+            (-1, 0, (1969, 12, 31)),
+            (86399, 0, (1970, 1, 1)),
+            (946684800, 0, (2000, 1, 1)),
+            (946684800 - 1, 480, (2000, 1, 1)),
+            (946684800 + 3600 * 16, 480, (2000, 1, 2)),
+            (946684800 + 3600, -120, (1999, 12, 31)),
+        ];
+        for (secs, tz, std) in dataset {
+            assert_eq!(
+                std,
+                Date::from_unix_secs(secs, tz).gregorian(),
+                "{} {}",
+                secs,
+                tz
+            );
+        }
+    }
+
+    #[test]
+    fn priv_month_offsets() {
+        for year_type in [YearType::Common, YearType::Leap] {
+            for month in 1..=12 {
+                let std = match month {
+                    1 => 0,
+                    2 => 31,
+                    _ => 59 + (153 * (month - 3) + 2) / 5 + year_type.is_leap() as i32,
+                };
+                assert_eq!(std + 1, ordinal_day_number(month, 1, year_type));
+            }
+        }
     }
 }
@@ -1,7 +1,7 @@
 //! Utilities for converting between dates in different calendars.
 //!
 //! Currently, this crate mainly supports conversion into Chinese lunisolar
-//! calendar (modern version) since 1973, using modern astronomical data for
+//! calendar (modern version) from 1950 to 2100, using modern astronomical data for
 //! accurate prediction.
 //!
 //! # Examples
@@ -29,18 +29,30 @@
 //! assert_eq!(Ok((1999, Common(11), 25)), annus.ymd_for(date));
 //! ```
 //!
+//! # Optional features
+//!
+//! - `clock` (enabled by default): provides
+//!   [`Date::today`](date::Date::today) and
+//!   [`Date::days_from_today`](date::Date::days_from_today), the only functions
+//!   that read the system clock. Disabling it gates nothing else; the crate
+//!   always requires `std`
+//! - `serde`: implements `Serialize` and `Deserialize` for [`Date`](date::Date)
+//!   (as an ISO 8601 string), [`YearType`], [`chinese::Month`] and
+//!   [`chinese::NewMoon`]
+//!
 //! # Planned features
 //!
-//! - Gregorian computus (for calculating date of Easter)
-//!     - (Possibly) full Gregorian lunisolar calendar
+//! - (Possibly) full Gregorian lunisolar calendar, beyond the computus in
+//!   [`computus`]
 //! - Timezone-neutrual version of Chinese calendar (differs slightly from the
-//!     standard version in some corner cases)
-//! - Chinese calendar before 1973
+//!   standard version in some corner cases)
+//! - Chinese calendar before 1950
 //!
 //! I wrote this primarily for my own use, so the design and development of
 //! this crate will depend heavily on my personal need.
 
 pub mod chinese;
+pub mod computus;
 pub mod date;
 pub mod time_scales;
 
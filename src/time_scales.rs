@@ -6,7 +6,7 @@
 //!
 //! # Planned
 //!
-//! - Supoort UT1 before 1972 (inter- & extrapolation)
+//! - More accurate UT1 before 1972 (interpolating historical data points)
 
 use crate::date::Date;
 
@@ -27,6 +27,40 @@ pub struct Tdb(pub f64);
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Tt(pub f64);
 
+impl Tdb {
+    /// Creates a `Tdb` from a Gregorian calendar date and a clock time read in
+    /// TDB, with `year` as in [`Date::from_gregorian`].
+    ///
+    /// `second` is not limited to `0.0..60.0`, so that a time offset in seconds
+    /// can be added directly.
+    ///
+    /// Returns `None` if the date is out of supported range, or if `hour` is
+    /// not in `0..24` or `minute` not in `0..60`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::time_scales::Tdb;
+    /// let tdb = Tdb::from_gregorian(2000, 1, 1, 12, 0, 0.0).unwrap();
+    /// assert_eq!(2451545.0, tdb.0);
+    /// ```
+    pub fn from_gregorian(
+        year: i32,
+        month: i32,
+        day: i32,
+        hour: i32,
+        minute: i32,
+        second: f64,
+    ) -> Option<Self> {
+        if !(0..24).contains(&hour) || !(0..60).contains(&minute) {
+            return None;
+        }
+        let date = Date::from_gregorian(year, month, day)?;
+        let secs = (hour * 3600 + minute * 60) as f64 + second;
+        Some(Tdb(date.jdn() as f64 - 0.5 + secs / 86400.0))
+    }
+}
+
 impl From<Tdb> for Tt {
     fn from(tdb: Tdb) -> Tt {
         Tt(tdb.0)
@@ -62,7 +96,7 @@ impl From<Tdb> for Tai {
 /// This can be either UTC (coordinated universal time, based on TAI with leap
 /// seconds) or UT1 (mean solar time on the Prime Meridian) depending on the
 /// year. Specifically, UTC from 1972-01-01T00:00Z to the latest known leap
-/// second, UT1 otherwise.
+/// second, UT1 otherwise (approximated, see [`Ut::convert`]).
 ///
 /// Due to irregularity of Earth's rotation, conversion from TAI to UT1 relies
 /// on data points with inter-/extrapolation with
@@ -71,10 +105,19 @@ impl From<Tdb> for Tai {
 pub struct Ut(pub f64);
 
 impl Ut {
-    // XXX Liveri `Enum`-n por distingi inter UTC & UT1
     /// Tries to convert a TAI (or other time scale easily convertible to TAI)
     /// into UT.
     ///
+    /// An inserted leap second is smeared: during the two TAI seconds starting
+    /// from 23:59:59 UTC of a leap second date, UT advances by only one second,
+    /// after which it is exactly one more second behind TAI.
+    ///
+    /// Before 1972-01-01 and after the latest known leap second, UT1 is
+    /// extrapolated with the long-term fit of ΔT, offset so that it is
+    /// continuous with UTC at either end. This is only a rough approximation
+    /// for the past, which can be off by a minute or so around 1900. See
+    /// [`convert_with_kind`](Self::convert_with_kind) to tell the two apart.
+    ///
     /// # Example
     ///
     /// ```
@@ -83,12 +126,25 @@ impl Ut {
     /// let ut = Ut::convert(tdb);
     /// assert_eq!((1999, 12, 30), ut.date_in_timezone(0).gregorian());
     /// ```
+    pub fn convert<T>(time: T) -> Self
+    where
+        T: Into<Tai>,
+    {
+        Self::convert_with_kind(time).0
+    }
+    /// Same as [`convert`](Self::convert), but also tells whether the result is
+    /// UTC from the leap second table or extrapolated UT1.
     ///
-    /// # Panics
+    /// # Example
     ///
-    /// Does not currently support time before 1972-01-01 and will panic.
-    /// Working on it.
-    pub fn convert<T>(time: T) -> Self
+    /// ```
+    /// use kalendarilo::time_scales::{Tdb, Ut, UtKind};
+    /// let (_, kind) = Ut::convert_with_kind(Tdb(2451545.0)); // 2000
+    /// assert_eq!(UtKind::Utc, kind);
+    /// let (_, kind) = Ut::convert_with_kind(Tdb(2488070.0)); // 2100
+    /// assert_eq!(UtKind::Ut1, kind);
+    /// ```
+    pub fn convert_with_kind<T>(time: T) -> (Self, UtKind)
     where
         T: Into<Tai>,
     {
@@ -97,22 +153,27 @@ impl Ut {
             starts,
             ref leap_seconds,
             expires,
+            c1,
             c2,
         } = leap_seconds::data();
 
         if tai < starts {
-            todo!("UT before UTC (1972-01-01)");
-        } else if tai > expires {
+            let diff = leap_seconds::estimate(tai) + c1;
+            return (Ut(tai.0 - diff / 86400.0), UtKind::Ut1);
+        } else if tai >= expires {
             let diff = leap_seconds::estimate(tai) + c2;
-            return Ut(tai.0 - diff / 86400.0); // NOTE UT1, ne UTC
+            return (Ut(tai.0 - diff / 86400.0), UtKind::Ut1);
         }
 
         let ls = match leap_seconds.partition_point(|ls| ls.tai <= tai) {
-            0 => return Ut(tai.0 - 10.0 / 86400.0),
+            0 => return (Ut(tai.0 - 10.0 / 86400.0), UtKind::Utc),
             i => &leap_seconds[i - 1],
         };
-        let leap = (tai.0 - ls.tai.0).min(2.0) / 2.0;
-        Ut(tai.0 - (ls.delta_secs as f64 + leap) / 86400.0)
+        // Smear the inserted second over the two TAI seconds of 23:59:59 and
+        // 23:59:60 UTC, so that UT stays monotonic
+        let leap = ((tai.0 - ls.tai.0) * 86400.0).min(2.0) / 2.0;
+        let ut = Ut(tai.0 - (ls.delta_secs as f64 + leap) / 86400.0);
+        (ut, UtKind::Utc)
     }
     /// Returns the date at the time point in timezone ahead (east) of UTC by
     /// `tz_offset_minutes`minutes.
@@ -128,15 +189,241 @@ impl Ut {
     /// assert_eq!((1999, 12, 30), ut.date_in_timezone(480).gregorian());
     /// ```
     pub fn date_in_timezone(&self, tz_offset_minutes: i32) -> Date {
-        let jdn = (self.0 + tz_offset_minutes as f64 / 1440.0).round() as u32;
-        Date::from_jdn(jdn)
+        self.local_day(tz_offset_minutes).0
+    }
+    /// Returns the civil date and time at the time point in timezone ahead
+    /// (east) of UTC by `tz_offset_minutes` minutes, with fractional seconds
+    /// truncated.
+    ///
+    /// The date is always the same as [`date_in_timezone`](Self::date_in_timezone).
+    /// Unlike [`civil_time`](Self::civil_time), an inserted leap second is not
+    /// reported separately: the smeared UT stays in 23:59:59 UTC for both TAI
+    /// seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::time_scales::{Tdb, Ut};
+    /// let ut = Ut::convert(Tdb(2460666.0)); // 2024-12-21T12:00 TDB
+    /// let dt = ut.datetime_in_timezone(480);
+    /// assert_eq!((2024, 12, 21), dt.date.gregorian());
+    /// assert_eq!((19, 58, 50), dt.hms());
+    /// assert_eq!("2024-12-21T19:58:50+08:00", dt.to_string());
+    /// ```
+    pub fn datetime_in_timezone(&self, tz_offset_minutes: i32) -> DateTime {
+        let (date, secs) = self.local_day(tz_offset_minutes);
+        DateTime {
+            date,
+            seconds_of_day: secs as u32,
+            tz_offset_minutes,
+        }
+    }
+    /// Returns the civil date and time at the time point in timezone ahead
+    /// (east) of UTC by `tz_offset_minutes` minutes, in `(date, hour, minute,
+    /// second)` format, with fractional seconds truncated.
+    ///
+    /// An inserted leap second is reported as second 60 (e.g. 23:59:60 UTC, or
+    /// 07:59:60 in UTC+8) instead of rolling over into the next minute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalendarilo::time_scales::Ut;
+    /// let ut = Ut(2451545.0); // 2000-01-01T12:00Z
+    /// let (date, h, m, s) = ut.civil_time(480);
+    /// assert_eq!(((2000, 1, 1), 20, 0, 0), (date.gregorian(), h, m, s));
+    /// ```
+    pub fn civil_time(&self, tz_offset_minutes: i32) -> (Date, u8, u8, u8) {
+        let utc_secs = Self::seconds_of_day(self.0);
+        let utc_jdn = (self.0 + 0.5).floor() as u32;
+        if utc_secs >= 86399.5 && leap_seconds::is_leap_second_date(utc_jdn) {
+            // UT is smeared over 23:59:59 and the inserted 23:59:60, the
+            // latter half of which is the leap second
+            let minutes = 1439 + tz_offset_minutes;
+            let date = Date::from_jdn(utc_jdn) + minutes.div_euclid(1440);
+            let minutes = minutes.rem_euclid(1440);
+            return (date, (minutes / 60) as u8, (minutes % 60) as u8, 60);
+        }
+
+        let dt = self.datetime_in_timezone(tz_offset_minutes);
+        let (h, m, s) = dt.hms();
+        (dt.date, h, m, s)
+    }
+
+    fn seconds_of_day(jd: f64) -> f64 {
+        let t = jd + 0.5;
+        ((t - t.floor()) * 86400.0).min(86399.999)
+    }
+    /// The local date and seconds into it, both from the same floor so that
+    /// they never disagree at midnight.
+    pub(crate) fn local_day(&self, tz_offset_minutes: i32) -> (Date, f64) {
+        let local = self.0 + tz_offset_minutes as f64 / 1440.0;
+        let jdn = (local + 0.5).floor() as u32;
+        (Date::from_jdn(jdn), Self::seconds_of_day(local))
+    }
+}
+
+/// Indicates which kind of UT a [`Ut`] value is, see
+/// [`Ut::convert_with_kind`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum UtKind {
+    /// Coordinated universal time, exact within the leap second table
+    Utc,
+    /// Extrapolated UT1, only an estimate
+    Ut1,
+}
+
+/// A civil date and time of day in a fixed timezone, as returned by
+/// [`Ut::datetime_in_timezone`].
+///
+/// `Display` formats it in ISO 8601, e.g. `2000-01-01T20:00:00+08:00`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DateTime {
+    /// The local date
+    pub date: Date,
+    /// Seconds since local midnight, `0..86400`
+    pub seconds_of_day: u32,
+    /// Timezone offset ahead (east) of UTC, in minutes
+    pub tz_offset_minutes: i32,
+}
+
+impl DateTime {
+    /// Returns the time of day in `(hour, minute, second)` format.
+    pub fn hms(&self) -> (u8, u8, u8) {
+        let secs = self.seconds_of_day;
+        (
+            (secs / 3600) as u8,
+            (secs / 60 % 60) as u8,
+            (secs % 60) as u8,
+        )
     }
 }
 
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (h, m, s) = self.hms();
+        let sign = if self.tz_offset_minutes < 0 { '-' } else { '+' };
+        let tz = self.tz_offset_minutes.unsigned_abs();
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}{}{:02}:{:02}",
+            self.date,
+            h,
+            m,
+            s,
+            sign,
+            tz / 60,
+            tz % 60
+        )
+    }
+}
+
+/// A time point in any of the time scales above, represented in Julian date
+/// (JD), for choosing the time scale at runtime.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::time_scales::{TimeScale, TimeScaleKind};
+///
+/// let tt = TimeScale::Tai(2451545.0).convert_to(TimeScaleKind::Tt);
+/// assert_eq!(TimeScaleKind::Tt, tt.kind());
+/// assert!((tt.jd() - 2451545.0 - 32.184 / 86400.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TimeScale {
+    /// TDB, as a Julian date, see [`Tdb`]
+    Tdb(f64),
+    /// TT, as a Julian date, see [`Tt`]
+    Tt(f64),
+    /// TAI, as a Julian date, see [`Tai`]
+    Tai(f64),
+    /// UT, as a Julian date, see [`Ut`]
+    Ut(f64),
+}
+
+/// The time scale of a [`TimeScale`], without the value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TimeScaleKind {
+    /// Barycentric dynamical time
+    Tdb,
+    /// Terrestrial time
+    Tt,
+    /// International atomic time
+    Tai,
+    /// Universal time, UTC or extrapolated UT1 as in [`Ut::convert`]
+    Ut,
+}
+
+impl TimeScale {
+    /// Returns the time scale of the value.
+    pub fn kind(&self) -> TimeScaleKind {
+        match self {
+            TimeScale::Tdb(_) => TimeScaleKind::Tdb,
+            TimeScale::Tt(_) => TimeScaleKind::Tt,
+            TimeScale::Tai(_) => TimeScaleKind::Tai,
+            TimeScale::Ut(_) => TimeScaleKind::Ut,
+        }
+    }
+    /// Returns the Julian date in its own time scale.
+    pub fn jd(&self) -> f64 {
+        match *self {
+            TimeScale::Tdb(jd) | TimeScale::Tt(jd) | TimeScale::Tai(jd) | TimeScale::Ut(jd) => jd,
+        }
+    }
+    /// Converts the time point into time scale `kind`, same as the `From`
+    /// impls and [`Ut::convert`].
+    ///
+    /// Conversion from UT is done by inverting [`Ut::convert`] iteratively.
+    pub fn convert_to(&self, kind: TimeScaleKind) -> TimeScale {
+        let tai = match *self {
+            TimeScale::Tdb(jd) => Tai::from(Tdb(jd)),
+            TimeScale::Tt(jd) => Tai::from(Tt(jd)),
+            TimeScale::Tai(jd) => Tai(jd),
+            TimeScale::Ut(jd) => {
+                // Converges at a rate of at least 1/2 per iteration (during
+                // the smeared leap seconds)
+                let mut tai = Tai(jd);
+                for _ in 0..64 {
+                    let next = Tai(jd + (tai.0 - Ut::convert(tai).0));
+                    if next == tai {
+                        break;
+                    }
+                    tai = next;
+                }
+                tai
+            }
+        };
+        match kind {
+            TimeScaleKind::Tdb => TimeScale::Tdb(Tt::from(tai).0),
+            TimeScaleKind::Tt => TimeScale::Tt(Tt::from(tai).0),
+            TimeScaleKind::Tai => TimeScale::Tai(tai.0),
+            TimeScaleKind::Ut => TimeScale::Ut(Ut::convert(tai).0),
+        }
+    }
+}
+
+/// Returns the last date covered by the built-in leap second table.
+///
+/// [`Ut::convert`] gives UTC up to 24:00 UTC at the end of this date, and
+/// extrapolated UT1 from then on, so results beyond it are estimates and may
+/// be off by a few seconds.
+///
+/// # Example
+///
+/// ```
+/// use kalendarilo::time_scales::leap_second_table_expiry;
+/// assert_eq!((2021, 12, 31), leap_second_table_expiry().gregorian());
+/// ```
+pub fn leap_second_table_expiry() -> Date {
+    let (y, m, d) = leap_seconds::DATE_EXPIRES;
+    Date::from_gregorian(y, m, d).unwrap()
+}
+
 mod leap_seconds {
     use super::{Tai, Tt};
     use crate::date::Date;
-    use std::sync::Once;
+    use std::sync::OnceLock;
 
     pub const DATES: &[(i32, i32, i32)] = &[
         (1972, 6, 30),
@@ -174,6 +461,9 @@ mod leap_seconds {
         pub starts: Tai,
         pub leap_seconds: Vec<LeapSecond>,
         pub expires: Tai,
+        /// Offsets of [`estimate`] for TAI - UT1 before `starts` and after
+        /// `expires`, respectively
+        pub c1: f64,
         pub c2: f64,
     }
     #[derive(Debug, Clone)]
@@ -182,43 +472,44 @@ mod leap_seconds {
         pub delta_secs: i32,
     }
 
-    static mut COMPUTED: Data = Data {
-        starts: Tai(0.0),
-        leap_seconds: vec![],
-        expires: Tai(0.0),
-        c2: 0.0,
-    };
-    static INIT: Once = Once::new();
+    static COMPUTED: OnceLock<Data> = OnceLock::new();
 
     pub fn data() -> &'static Data {
-        INIT.call_once(|| {
+        COMPUTED.get_or_init(|| {
+            // JDN is at noon, UTC starts at midnight
             let starts =
-                Tai(Date::from_gregorian(1972, 1, 1).unwrap().jdn() as f64 + 10.0 / 86400.0);
-            unsafe {
-                COMPUTED.starts = starts;
-                COMPUTED.leap_seconds.reserve_exact(DATES.len());
-            }
+                Tai(Date::from_gregorian(1972, 1, 1).unwrap().jdn() as f64 - 0.5 + 10.0 / 86400.0);
+            let c1 = 10.0 - estimate(starts);
+            let mut leap_seconds = Vec::with_capacity(DATES.len());
             for (delta_secs, &(y, m, d)) in (10..).zip(DATES) {
                 let jdn = Date::from_gregorian(y, m, d)
                     .unwrap_or_else(|| panic!("date not recognized: {:?}", (y, m, d)))
                     .jdn();
                 let tai = Tai(jdn as f64 + (43199 + delta_secs) as f64 / 86400.0);
-                unsafe {
-                    COMPUTED.leap_seconds.push(LeapSecond { tai, delta_secs });
-                }
+                leap_seconds.push(LeapSecond { tai, delta_secs });
             }
             let (y, m, d) = DATE_EXPIRES;
             let jdn = Date::from_gregorian(y, m, d)
                 .unwrap_or_else(|| panic!("date not recognized: {:?}", (y, m, d)))
                 .jdn();
-            let tai = Tai(jdn as f64 + (43199 + 10 + DATES.len()) as f64 / 86400.0);
-            let c2 = (DATES.len() + 10) as f64 - estimate(tai);
-            unsafe {
-                COMPUTED.expires = tai;
-                COMPUTED.c2 = c2;
+            // 24:00 UTC of the last covered date
+            let expires = Tai(jdn as f64 + (43200 + 10 + DATES.len()) as f64 / 86400.0);
+            let c2 = (DATES.len() + 10) as f64 - estimate(expires);
+            Data {
+                starts,
+                leap_seconds,
+                expires,
+                c1,
+                c2,
             }
-        });
-        unsafe { &COMPUTED }
+        })
+    }
+
+    /// Whether a leap second was inserted at the end of the (UTC) date.
+    pub fn is_leap_second_date(jdn: u32) -> bool {
+        DATES
+            .iter()
+            .any(|&(y, m, d)| Date::from_gregorian(y, m, d).map(|date| date.jdn()) == Some(jdn))
     }
 
     pub fn estimate<T: Into<Tt>>(tt: T) -> f64 {
@@ -252,6 +543,257 @@ mod tests {
         );
     }
 
+    #[test]
+    fn leap_second_data() {
+        let data = leap_seconds::data();
+        assert_eq!(leap_seconds::DATES.len(), data.leap_seconds.len());
+        for (pair, delta) in data.leap_seconds.windows(2).zip(10..) {
+            assert_eq!((delta, delta + 1), (pair[0].delta_secs, pair[1].delta_secs));
+            assert!(pair[0].tai < pair[1].tai);
+        }
+        assert!(data.starts < data.leap_seconds[0].tai);
+        assert!(data.leap_seconds.last().unwrap().tai < data.expires);
+        assert!(std::ptr::eq(data, leap_seconds::data()));
+        // Values unchanged
+        assert_eq!(2451543.1662962968, Ut::convert(Tai(2451543.166666667)).0);
+        assert_eq!(2457754.4995833333, Ut::convert(Tai(2457754.5)).0);
+        // Continuous across the end of the table
+        let secs = |tai: Tai| (tai.0 - Ut::convert(tai).0) * 86400.0;
+        let before = Tai(data.expires.0 - 1e-3 / 86400.0);
+        let after = Tai(data.expires.0 + 1e-3 / 86400.0);
+        assert!((secs(before) - secs(after)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn before_utc() {
+        let secs = |tai: Tai, ut: Ut| (tai.0 - ut.0) * 86400.0;
+        // 1900-01-01T12:00 TT, when ΔT was about -2.8 s; the estimate may be
+        // off by a minute or so
+        let tdb = Tdb(2415021.0);
+        let ut = Ut::convert(tdb);
+        let delta_t = (tdb.0 - ut.0) * 86400.0;
+        assert!((delta_t - -2.8).abs() < 90.0, "{}", delta_t);
+        assert_eq!((1900, 1, 1), ut.date_in_timezone(0).gregorian());
+        assert_eq!((1900, 1, 1), ut.date_in_timezone(480).gregorian());
+        // Continuous with UTC
+        let starts = leap_seconds::data().starts;
+        let before = Tai(starts.0 - 1e-3 / 86400.0);
+        assert!((secs(before, Ut::convert(before)) - 10.0).abs() < 1e-3);
+        let ut = Ut::convert(Tai(starts.0 - 1.0 / 86400.0));
+        let (date, h, min, s) = ut.civil_time(0);
+        assert_eq!(((1971, 12, 31), 23, 59, 59), (date.gregorian(), h, min, s));
+        // Monotonic
+        let mut last = Ut::convert(Tdb(2305447.5)); // 1600
+        for i in 1..=400 {
+            let ut = Ut::convert(Tdb(2305447.5 + 365.25 * i as f64));
+            assert!(ut > last);
+            last = ut;
+        }
+    }
+
+    #[test]
+    fn leap_second_boundaries() {
+        let secs = |tai: Tai, ut: Ut| ((tai.0 - ut.0) * 86400.0 * 1000.0).round() / 1000.0;
+        for (delta, (y, m, d)) in [(10.0, (1972, 6, 30)), (36.0, (2016, 12, 31))] {
+            let jdn = Date::from_gregorian(y, m, d).unwrap().jdn() as f64;
+            // TAI at 23:59:59 UTC
+            let ls = jdn + (43199.0 + delta) / 86400.0;
+            let dataset = [
+                (-86400.0, delta),
+                (-1.0, delta),
+                (0.0, delta),
+                (1.0, delta + 0.5),
+                (2.0, delta + 1.0),
+                (3.0, delta + 1.0),
+                (86400.0, delta + 1.0),
+            ];
+            for (offset, std) in dataset {
+                let tai = Tai(ls + offset / 86400.0);
+                assert_eq!(
+                    std,
+                    secs(tai, Ut::convert(tai)),
+                    "{:?} {}s",
+                    (y, m, d),
+                    offset
+                );
+            }
+            let next = Date::from_jdn(jdn as u32) + 1;
+            let (date, h, min, s) = Ut::convert(Tai(ls + 2.0 / 86400.0)).civil_time(0);
+            assert_eq!((next, 0, 0, 0), (date, h, min, s));
+        }
+        // UTC starts at 1972-01-01T00:00Z with TAI - UTC = 10s
+        let midnight = Date::from_gregorian(1972, 1, 1).unwrap().jdn() as f64 - 0.5;
+        let starts = Tai(midnight + 10.0 / 86400.0);
+        assert_eq!(starts, leap_seconds::data().starts);
+        let tai = Tai(starts.0 + 1.5 / 86400.0);
+        let ut = Ut::convert(tai);
+        assert_eq!(10.0, secs(tai, ut));
+        let (date, h, min, s) = ut.civil_time(0);
+        assert_eq!(((1972, 1, 1), 0, 0, 1), (date.gregorian(), h, min, s));
+    }
+
+    #[test]
+    fn civil_time_leap_second() {
+        // 2016-12-31 ends with a leap second
+        let midnight = Date::from_gregorian(2017, 1, 1).unwrap().jdn() as f64 - 0.5;
+        let dataset = [
+            (-1.5, 0, ((2016, 12, 31), 23, 59, 58)),
+            (-0.75, 0, ((2016, 12, 31), 23, 59, 59)),
+            (-0.25, 0, ((2016, 12, 31), 23, 59, 60)),
+            (-0.25, 480, ((2017, 1, 1), 7, 59, 60)),
+            (-0.25, -300, ((2016, 12, 31), 18, 59, 60)),
+            (0.25, 0, ((2017, 1, 1), 0, 0, 0)),
+            (0.25, 480, ((2017, 1, 1), 8, 0, 0)),
+        ];
+        for (secs, tz, std) in dataset {
+            let ut = Ut(midnight + secs / 86400.0);
+            let (date, h, m, s) = ut.civil_time(tz);
+            assert_eq!(std, (date.gregorian(), h, m, s), "{}s, tz {}", secs, tz);
+        }
+        // No leap second at the end of 2017-12-31
+        let ut = Ut(midnight + (365.0 * 86400.0 - 0.25) / 86400.0);
+        assert_eq!(59, ut.civil_time(0).3);
+    }
+
+    #[test]
+    fn ut_kind() {
+        let data = leap_seconds::data();
+        let eps = 1e-3 / 86400.0;
+        let dataset = [
+            (Tai(2305447.5), UtKind::Ut1),
+            (Tai(data.starts.0 - eps), UtKind::Ut1),
+            (data.starts, UtKind::Utc),
+            (Tai(2451545.0), UtKind::Utc),
+            (Tai(data.expires.0 - eps), UtKind::Utc),
+            (data.expires, UtKind::Ut1),
+            (Tai(2488070.0), UtKind::Ut1),
+        ];
+        for (tai, std) in dataset {
+            let (ut, kind) = Ut::convert_with_kind(tai);
+            assert_eq!(std, kind, "{:?}", tai);
+            assert_eq!(Ut::convert(tai), ut);
+        }
+        let expiry = leap_second_table_expiry().jdn() as f64;
+        assert_eq!(UtKind::Utc, Ut::convert_with_kind(Tdb(expiry)).1);
+        assert_eq!(UtKind::Ut1, Ut::convert_with_kind(Tdb(expiry + 1.0)).1);
+    }
+
+    #[test]
+    fn datetime() {
+        // 2024 winter solstice
+        let ut = Ut::convert(Tdb(2460665.890085318));
+        let dt = ut.datetime_in_timezone(480);
+        assert_eq!((2024, 12, 21), dt.date.gregorian());
+        assert_eq!((17, 20, 34), dt.hms());
+        assert_eq!(17 * 3600 + 20 * 60 + 34, dt.seconds_of_day);
+        assert_eq!("2024-12-21T17:20:34+08:00", dt.to_string());
+        assert_eq!(
+            "2024-12-21T03:50:34-05:30",
+            ut.datetime_in_timezone(-330).to_string()
+        );
+        assert_eq!(
+            "2024-12-21T09:20:34+00:00",
+            ut.datetime_in_timezone(0).to_string()
+        );
+
+        // Consistent with `date_in_timezone` around midnight, down to the
+        // last representable value before it
+        let midnight = Date::from_gregorian(2000, 1, 1).unwrap().jdn() as f64 - 0.5;
+        let mut points = vec![midnight, f64::from_bits(midnight.to_bits() - 1)];
+        points.extend((-20..=20).map(|i| midnight + i as f64 * 1e-4 / 86400.0));
+        for jd in points {
+            for tz in [0, 480, -300] {
+                let ut = Ut(jd - tz as f64 / 1440.0);
+                let dt = ut.datetime_in_timezone(tz);
+                assert_eq!(ut.date_in_timezone(tz), dt.date, "{} {}", jd, tz);
+                assert!(dt.seconds_of_day < 86400);
+                let before = jd < midnight;
+                assert_eq!(before, dt.seconds_of_day == 86399, "{} {}", jd, tz);
+                assert_eq!(before, dt.date.gregorian().0 == 1999, "{} {}", jd, tz);
+            }
+        }
+
+        // The inserted leap second is not labeled
+        let midnight = Date::from_gregorian(2017, 1, 1).unwrap().jdn() as f64 - 0.5;
+        let ut = Ut(midnight - 0.25 / 86400.0);
+        assert_eq!(60, ut.civil_time(0).3);
+        assert_eq!((23, 59, 59), ut.datetime_in_timezone(0).hms());
+    }
+
+    #[test]
+    fn tdb_from_gregorian() {
+        assert_eq!(
+            Some(Tdb(2451545.0)),
+            Tdb::from_gregorian(2000, 1, 1, 12, 0, 0.0)
+        );
+        assert_eq!(
+            Some(Tdb(2451544.5)),
+            Tdb::from_gregorian(2000, 1, 1, 0, 0, 0.0)
+        );
+        assert_eq!(
+            Some(Tdb(2451545.25)),
+            Tdb::from_gregorian(2000, 1, 1, 18, 0, 0.0)
+        );
+        let tdb = Tdb::from_gregorian(1999, 12, 30, 16, 0, 0.0).unwrap();
+        assert!((tdb.0 - 2451543.166666667).abs() < 1e-6);
+        assert_eq!(
+            (1999, 12, 30),
+            Ut::convert(tdb).date_in_timezone(0).gregorian()
+        );
+        assert_eq!(None, Tdb::from_gregorian(-4714, 1, 1, 0, 0, 0.0));
+        for (h, m) in [(24, 0), (-1, 0), (0, 60), (0, -1), (i32::MAX, i32::MAX)] {
+            assert_eq!(
+                None,
+                Tdb::from_gregorian(2000, 1, 1, h, m, 0.0),
+                "{:?}",
+                (h, m)
+            );
+        }
+        assert_eq!(
+            Tdb::from_gregorian(2000, 1, 1, 12, 1, 0.0),
+            Tdb::from_gregorian(2000, 1, 1, 12, 0, 60.0)
+        );
+    }
+
+    #[test]
+    fn dynamic_conversion() {
+        use TimeScaleKind as K;
+        let tdb = Tdb(2451543.166666667);
+        let dynamic = TimeScale::Tdb(tdb.0);
+        assert_eq!(TimeScale::Tdb(tdb.0), dynamic.convert_to(K::Tdb));
+        assert_eq!(TimeScale::Tt(Tt::from(tdb).0), dynamic.convert_to(K::Tt));
+        assert_eq!(TimeScale::Tai(Tai::from(tdb).0), dynamic.convert_to(K::Tai));
+        assert_eq!(TimeScale::Ut(Ut::convert(tdb).0), dynamic.convert_to(K::Ut));
+
+        let ut = dynamic.convert_to(K::Ut);
+        for kind in [K::Tdb, K::Tt, K::Tai, K::Ut] {
+            let back = ut.convert_to(kind);
+            assert_eq!(kind, back.kind());
+            let expected = dynamic.convert_to(kind).jd();
+            assert!((back.jd() - expected).abs() < 1e-4 / 86400.0, "{:?}", kind);
+        }
+        // Inside a leap second
+        let jdn = Date::from_gregorian(2016, 12, 31).unwrap().jdn() as f64;
+        let tai = TimeScale::Tai(jdn + (43199.0 + 36.0 + 1.5) / 86400.0);
+        let back = tai.convert_to(K::Ut).convert_to(K::Tai);
+        assert!((back.jd() - tai.jd()).abs() < 1e-4 / 86400.0);
+    }
+
+    #[test]
+    fn table_expiry() {
+        let expiry = leap_second_table_expiry();
+        assert_eq!((2021, 12, 31), expiry.gregorian());
+        // The whole last second of the date is still UTC
+        let end = (expiry + 1).jdn() as f64 - 0.5;
+        let eps = 1e-3 / 86400.0;
+        let (ut, kind) = Ut::convert_with_kind(Tai(end - eps + 37.0 / 86400.0));
+        assert_eq!(UtKind::Utc, kind);
+        assert_eq!(expiry, ut.date_in_timezone(0));
+        let (ut, kind) = Ut::convert_with_kind(Tai(end + eps + 37.0 / 86400.0));
+        assert_eq!(UtKind::Ut1, kind);
+        assert_eq!(expiry + 1, ut.date_in_timezone(0));
+    }
+
     #[test]
     fn playing_with() {
         let tdb = Tdb(2462501.166666667 + 5.647029454550371); // 2030 小寒